@@ -31,11 +31,13 @@ use ctrl_z_repository::commit::trim_trailers;
 
 pub mod change;
 pub mod changelog;
+pub mod config;
 mod error;
 pub mod revision;
 pub mod scopes;
 
 use change::Change;
+use config::Config;
 pub use error::{Error, Result};
 use revision::Revision;
 use scopes::Scopes;
@@ -52,6 +54,8 @@ use scopes::Scopes;
 /// which are handled outside of changesets. Changesets only describe.
 #[derive(Debug)]
 pub struct Changeset<'a> {
+    /// Changeset configuration.
+    config: Config,
     /// Scope set.
     scopes: Scopes,
     /// List of revisions.
@@ -72,6 +76,26 @@ impl Changeset<'_> {
     /// This method returns [`Error::Scopes`] if the scope set can't be built
     /// from the workspace, which should practically never happen.
     pub fn new<T>(workspace: &Workspace<T>) -> Result<Self>
+    where
+        T: Manifest,
+    {
+        Self::with_config(workspace, Config::default())
+    }
+
+    /// Creates a changeset with the given configuration.
+    ///
+    /// Scopes are added in the order of [`Workspace::packages`], i.e., sorted
+    /// by package name, so the indices of scopes and version increments match
+    /// the nodes of [`Workspace::dependents`] and the order expected by
+    /// [`Workspace::apply`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Scopes`] if the scope set can't be built
+    /// from the workspace, which should practically never happen.
+    pub fn with_config<T>(
+        workspace: &Workspace<T>, config: Config,
+    ) -> Result<Self>
     where
         T: Manifest,
     {
@@ -84,11 +108,33 @@ impl Changeset<'_> {
         let scopes = builder.build()?;
         Ok(Self {
             increments: vec![None; scopes.len()],
+            config,
             scopes,
             revisions: Vec::new(),
         })
     }
 
+    /// Attempts to create a change from a string.
+    ///
+    /// In addition to [`Config::parse`], this method ensures that the scope
+    /// of the change, if any, refers to a package in the scope set.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Config::parse`], as well as
+    /// [`Error::Scope`][] if the scope of the change is unknown.
+    ///
+    /// [`Error::Scope`]: crate::changeset::change::Error::Scope
+    pub fn parse(&self, value: &str) -> change::Result<Change> {
+        let change = self.config.parse(value)?;
+        match change.scope() {
+            Some(scope) if self.scopes.find(scope).is_none() => {
+                Err(change::Error::Scope)
+            }
+            _ => Ok(change),
+        }
+    }
+
     /// Returns the summary.
     ///
     /// The summary is given by the commit's body of the latest revision in the
@@ -116,6 +162,12 @@ impl Changeset<'_> {
 
 #[allow(clippy::must_use_candidate)]
 impl Changeset<'_> {
+    /// Returns a reference to the changeset configuration.
+    #[inline]
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     /// Returns a reference to the scope set.
     #[inline]
     pub fn scopes(&self) -> &Scopes {
@@ -146,3 +198,52 @@ impl Changeset<'_> {
         self.revisions.is_empty()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod with_config {
+        use std::error::Error;
+        use std::fs;
+        use tempfile::TempDir;
+
+        use ctrl_z_project::{Cargo, Workspace};
+
+        use crate::changeset::{Changeset, Config};
+
+        #[test]
+        fn handles_paths_and_names_in_different_order(
+        ) -> Result<(), Box<dyn Error>> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            for (path, name) in [("core", "zeta"), ("util", "alpha")] {
+                let path = root.join("packages").join(path);
+                fs::create_dir_all(&path)?;
+                fs::write(
+                    path.join("Cargo.toml"),
+                    format!(
+                        "[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n"
+                    ),
+                )?;
+            }
+            fs::write(
+                root.join("Cargo.toml"),
+                "[workspace]\nmembers = [\"packages/core\", \"packages/util\"]\n",
+            )?;
+
+            // Scope indices must match the nodes of the dependents graph
+            let workspace = Workspace::<Cargo>::read(root.join("Cargo.toml"))?;
+            let changeset =
+                Changeset::with_config(&workspace, Config::default())?;
+            let dependents = workspace.dependents()?;
+            let index = changeset.scopes().get("packages/core/src/lib.rs");
+            assert_eq!(index, changeset.scopes().find("zeta"));
+            assert_eq!(dependents[index.expect("scope")].name(), Some("zeta"));
+            Ok(())
+        }
+    }
+}
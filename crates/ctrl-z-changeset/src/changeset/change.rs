@@ -45,6 +45,8 @@ pub use kind::Kind;
 pub struct Change {
     /// Change kind.
     kind: Kind,
+    /// Change scope.
+    scope: Option<String>,
     /// Change summary.
     summary: String,
     /// Change is breaking.
@@ -63,7 +65,8 @@ impl Change {
     /// ```
     /// # use std::error::Error;
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use ctrl_z_changeset::{Change, Increment};
+    /// use ctrl_z_changeset::Change;
+    /// use ctrl_z_project::version::Increment;
     ///
     /// // Create increment from change
     /// let change: Change = "fix: summary".parse()?;
@@ -89,6 +92,31 @@ impl Change {
             Some(increment)
         }
     }
+
+    /// Marks the change as breaking.
+    ///
+    /// Breaking changes can be declared either in the summary, denoted by an
+    /// exclamation mark, or in the footer via a `BREAKING CHANGE` trailer, in
+    /// which case the change is marked after parsing the summary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ctrl_z_changeset::Change;
+    ///
+    /// // Create change from string and mark as breaking
+    /// let change: Change = "fix: summary".parse()?;
+    /// assert!(change.into_breaking().is_breaking());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_breaking(mut self) -> Self {
+        self.is_breaking = true;
+        self
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -99,6 +127,12 @@ impl Change {
         self.kind
     }
 
+    /// Returns the change scope, if any.
+    #[inline]
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
     /// Returns the change summary.
     #[inline]
     pub fn summary(&self) -> &str {
@@ -124,8 +158,9 @@ impl FromStr for Change {
     /// # Errors
     ///
     /// This methods return [`Error::Format`][], if the string does not adhere
-    /// to conventional commits format (without scopes), and [`Error::Kind`][],
-    /// if the string does not correspond to a valid [`Kind`] variant.
+    /// to conventional commits format, and [`Error::Kind`][], if the string
+    /// does not correspond to a valid [`Kind`] variant. Note that the scope is
+    /// optional, but if given, must not be empty or contain whitespace.
     ///
     /// # Examples
     ///
@@ -135,7 +170,8 @@ impl FromStr for Change {
     /// use ctrl_z_changeset::Change;
     ///
     /// // Create change from string
-    /// let change: Change = "fix: summary".parse()?;
+    /// let change: Change = "fix(ctrl-z): summary".parse()?;
+    /// assert_eq!(change.scope(), Some("ctrl-z"));
     /// # Ok(())
     /// # }
     /// ```
@@ -146,13 +182,32 @@ impl FromStr for Change {
 
         // Check if we have a breaking change, denoted by an exclamation mark
         // at the end of the string, and extract and parse the change kind
-        let (kind, is_breaking) = match kind.split_once('!') {
-            Some((kind, _)) => (Kind::from_str(kind)?, true),
-            None => (Kind::from_str(kind)?, false),
+        let (kind, is_breaking) = match kind.strip_suffix('!') {
+            Some(kind) => (kind, true),
+            None => (kind, false),
+        };
+
+        // Extract the scope, if any, which is enclosed in parentheses, and must
+        // be a single word, e.g., the name of a package
+        let (kind, scope) = match kind.split_once('(') {
+            Some((kind, scope)) => {
+                let scope = scope.strip_suffix(')').ok_or(Error::Format)?;
+                if scope.is_empty()
+                    || scope.contains(|char: char| {
+                        char.is_whitespace() || char == '(' || char == ')'
+                    })
+                {
+                    return Err(Error::Format);
+                }
+                (kind, Some(scope.to_string()))
+            }
+            None => (kind, None),
         };
 
-        // Ensure summary has no leading or trailing whitespace, as we want to
-        // be as strict as possible with the format of commit messages
+        // Parse change kind, and ensure summary has no leading or trailing
+        // whitespace, as we want to be as strict as possible with the format
+        // of commit messages
+        let kind = Kind::from_str(kind)?;
         if summary != summary.trim() {
             return Err(Error::Whitespace);
         }
@@ -191,7 +246,12 @@ impl FromStr for Change {
 
         // Return change
         let summary = summary.to_string();
-        Ok(Change { kind, summary, is_breaking })
+        Ok(Change {
+            kind,
+            scope,
+            summary,
+            is_breaking,
+        })
     }
 }
 
@@ -201,6 +261,11 @@ impl fmt::Display for Change {
     /// Formats the change for display.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.kind.fmt(f)?;
+        if let Some(scope) = &self.scope {
+            f.write_char('(')?;
+            f.write_str(scope)?;
+            f.write_char(')')?;
+        }
         if self.is_breaking {
             f.write_char('!')?;
         }
@@ -242,19 +307,41 @@ mod tests {
             Ok(())
         }
 
+        #[test]
+        fn handles_scope() -> Result {
+            let change = Change::from_str("fix(ctrl-z)!: summary")?;
+            assert_eq!(change.kind, Kind::Fix);
+            assert_eq!(change.scope.as_deref(), Some("ctrl-z"));
+            assert_eq!(change.is_breaking, true);
+            assert_eq!(change.summary, "summary");
+            Ok(())
+        }
+
         #[test]
         fn errors_on_invalid_format() {
             for format in [
                 "fix:summary",
-                "fix:  summary",
                 "fix :summary",
                 "fix summary",
+                "fix(): summary",
+                "fix(ctrl z): summary",
+                "fix(ctrl-z: summary",
             ] {
                 let res = Change::from_str(format);
                 assert!(matches!(res, Err(Error::Format)));
             }
         }
 
+        #[test]
+        fn errors_on_whitespace() {
+            for format in
+                ["fix:  summary", "fix: summary ", "fix(ctrl-z):  summary"]
+            {
+                let res = Change::from_str(format);
+                assert!(matches!(res, Err(Error::Whitespace)));
+            }
+        }
+
         #[test]
         fn errors_on_invalid_kind() {
             for format in [
@@ -41,6 +41,9 @@ pub enum Error {
     /// Invalid kind.
     #[error("invalid kind")]
     Kind,
+    /// Unknown scope.
+    #[error("unknown scope")]
+    Scope,
     /// Message has leading or trailing whitespace.
     #[error("message has leading or trailing whitespace")]
     Whitespace,
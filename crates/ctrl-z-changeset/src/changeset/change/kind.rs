@@ -25,6 +25,7 @@
 
 //! Change kind.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
@@ -35,7 +36,10 @@ use super::error::{Error, Result};
 // ----------------------------------------------------------------------------
 
 /// Change kind.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum Kind {
     /// Feature.
     Feature,
@@ -25,15 +25,28 @@
 
 //! Changelog.
 
-use std::collections::BTreeMap;
-use std::fmt;
+use semver::Version;
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use std::collections::btree_map::Values;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{fmt, iter};
 
+use ctrl_z_repository::commit::Signature;
+use ctrl_z_repository::{Date, Forge};
+
+use super::config::Config;
 use super::revision::Revision;
 use super::scopes::Scopes;
 use super::Changeset;
 
+mod contributor;
+pub mod renderer;
 mod section;
 
+pub use contributor::Contributor;
+use renderer::{Markdown, Renderer};
+use section::Linked;
 pub use section::{Category, Section};
 
 // ----------------------------------------------------------------------------
@@ -48,15 +61,39 @@ pub use section::{Category, Section};
 /// into their own section, which comes first.
 ///
 /// The changelog is solely intended for printing, which is why it implements
-/// [`fmt::Display`]. The output format is Markdown, as supported by GitHub.
+/// [`fmt::Display`]. The output format is Markdown, as supported by GitHub,
+/// but other formats can be rendered with a [`Renderer`].
 ///
 /// [`Changeset`]: crate::changeset::Changeset
 #[derive(Debug)]
 pub struct Changelog<'a> {
+    /// Changeset configuration.
+    config: &'a Config,
     /// Scope set.
     scopes: &'a Scopes,
     /// Sections grouped by category.
     sections: BTreeMap<Category, Section<'a>>,
+    /// Contributors, ordered by name.
+    contributors: Vec<Contributor>,
+    /// Version, if released.
+    version: Option<Version>,
+    /// Release date, if released.
+    date: Option<Date>,
+    /// Version summary.
+    summary: Option<&'a str>,
+    /// Forge for links, if any.
+    forge: Option<&'a Forge>,
+    /// Range of compared tags, if any.
+    range: Option<(String, String)>,
+}
+
+/// Range of compared tags, for serialization.
+#[derive(Serialize)]
+struct Range<'a> {
+    /// Previous tag.
+    from: &'a str,
+    /// Current tag.
+    to: &'a str,
 }
 
 // ----------------------------------------------------------------------------
@@ -68,8 +105,15 @@ impl Changeset<'_> {
     #[must_use]
     pub fn to_changelog(&self) -> Changelog<'_> {
         let mut changelog = Changelog {
+            config: &self.config,
             scopes: &self.scopes,
             sections: BTreeMap::default(),
+            contributors: Vec::new(),
+            version: None,
+            date: None,
+            summary: None,
+            forge: None,
+            range: None,
         };
 
         // Extend changelog with all revisions
@@ -81,30 +125,187 @@ impl Changeset<'_> {
 // ----------------------------------------------------------------------------
 
 impl<'a> Changelog<'a> {
+    /// Sets the version of the changelog.
+    #[must_use]
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the release date of the changelog.
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the version summary of the changelog.
+    #[must_use]
+    pub fn with_summary(mut self, summary: &'a str) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+
+    /// Sets the forge, so commits and issues are rendered as links.
+    #[must_use]
+    pub fn with_forge(mut self, forge: &'a Forge) -> Self {
+        self.forge = Some(forge);
+        self
+    }
+
+    /// Sets the range of compared tags, i.e., the previous and current tag.
+    #[must_use]
+    pub fn with_range<F, T>(mut self, from: F, to: T) -> Self
+    where
+        F: Into<String>,
+        T: Into<String>,
+    {
+        self.range = Some((from.into(), to.into()));
+        self
+    }
+
+    /// Marks contributors not included in the given email addresses as first
+    /// time contributors, e.g., to welcome them in the release notes.
+    ///
+    /// Email addresses are compared case-insensitively.
+    #[must_use]
+    pub fn with_known_contributors<I, S>(mut self, emails: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let known = emails
+            .into_iter()
+            .map(|email| email.as_ref().to_lowercase())
+            .collect::<BTreeSet<_>>();
+
+        // Mark all contributors that are not known as first time contributors
+        for contributor in &mut self.contributors {
+            let email = contributor.email().to_lowercase();
+            contributor.first = !known.contains(&email);
+        }
+        self
+    }
+
+    /// Returns the URL comparing the previous and current tag.
+    ///
+    /// This requires both, a forge and a range of compared tags to be set.
+    #[must_use]
+    pub fn compare(&self) -> Option<String> {
+        let forge = self.forge?;
+        let (from, to) = self.range.as_ref()?;
+        Some(forge.compare(from, to))
+    }
+
+    /// Renders the changelog with the given renderer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the errors of [`Renderer::render`], e.g., if the
+    /// changelog can't be rendered with a template.
+    pub fn render(&self, renderer: &dyn Renderer) -> super::Result<String> {
+        let mut value = String::new();
+        renderer.render(&mut value, self)?;
+        Ok(value)
+    }
+
     /// Adds a revision to the changelog.
     ///
     /// Note that only relevant changes are included in the changelog, which
     /// includes features, fixes, performance improvements and refactorings. In
     /// case the changeset does not include such changes, the changelog will be
-    /// empty, which is expected, since no release is necessary.
+    /// empty, which is expected, since no release is necessary. However, the
+    /// authors of all revisions are credited as contributors.
     pub fn add(&mut self, revision: &'a Revision<'a>) {
         let change = revision.change();
 
+        // Credit the author and co-authors of all revisions, regardless of the
+        // kind of change, since documentation and the like count as well
+        let author = revision.commit().author();
+        for signature in iter::once(&author).chain(revision.co_authors()) {
+            self.credit(signature);
+        }
+
         // Determine section category, create section and add revision - note
         // that we need to pass the scopes for rendering, as only indices are
         // stored, and not all types of changes are featured in the changelog,
         // so we skip those that are not
         if let Some(category) = change.into() {
+            let title = self.config.title(category);
             self.sections
                 .entry(category)
-                .or_insert_with(|| category.into())
+                .or_insert_with(|| Section::new(category, title))
                 .add(revision, self.scopes);
         }
     }
 }
 
-#[allow(clippy::must_use_candidate)]
 impl Changelog<'_> {
+    /// Adds a contributor, unless already credited, retaining the order.
+    fn credit(&mut self, signature: &Signature) {
+        let email = signature.email();
+        let mut iter = self.contributors.iter();
+        if iter
+            .any(|contributor| contributor.email().eq_ignore_ascii_case(email))
+        {
+            return;
+        }
+
+        // Insert contributor at the position determined by its name
+        let key = signature.name().to_lowercase();
+        let index = self.contributors.partition_point(|contributor| {
+            contributor.name().to_lowercase() <= key
+        });
+        self.contributors.insert(index, signature.into());
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<'a> Changelog<'a> {
+    /// Returns the version, if released.
+    #[inline]
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+
+    /// Returns the release date, if released.
+    #[inline]
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+
+    /// Returns the version summary, if any.
+    #[inline]
+    pub fn summary(&self) -> Option<&'a str> {
+        self.summary
+    }
+
+    /// Returns a reference to the forge, if any.
+    #[inline]
+    pub fn forge(&self) -> Option<&'a Forge> {
+        self.forge
+    }
+
+    /// Returns the range of compared tags, if any.
+    #[inline]
+    pub fn range(&self) -> Option<(&str, &str)> {
+        self.range
+            .as_ref()
+            .map(|(from, to)| (from.as_str(), to.as_str()))
+    }
+
+    /// Creates an iterator over the sections, ordered by category.
+    #[inline]
+    pub fn sections(&self) -> Values<'_, Category, Section<'a>> {
+        self.sections.values()
+    }
+
+    /// Returns a reference to the contributors, ordered by name.
+    #[inline]
+    pub fn contributors(&self) -> &[Contributor] {
+        &self.contributors
+    }
+
     /// Returns the number of changes.
     #[inline]
     pub fn len(&self) -> usize {
@@ -136,20 +337,35 @@ impl<'a> Extend<&'a Revision<'a>> for Changelog<'a> {
 
 // ----------------------------------------------------------------------------
 
+impl Serialize for Changelog<'_> {
+    /// Serializes the changelog.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let date = self.date.as_ref().map(ToString::to_string);
+        let range = self.range().map(|(from, to)| Range { from, to });
+        let sections = self.sections.values();
+        let sections = sections.map(|section| Linked(section, self.forge));
+
+        // Serialize changelog, with sections linking to the forge, if any
+        let mut state = serializer.serialize_struct("Changelog", 7)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("date", &date)?;
+        state.serialize_field("summary", &self.summary)?;
+        state.serialize_field("range", &range)?;
+        state.serialize_field("compare", &self.compare())?;
+        state.serialize_field("sections", &sections.collect::<Vec<_>>())?;
+        state.serialize_field("contributors", &self.contributors)?;
+        state.end()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 impl fmt::Display for Changelog<'_> {
     /// Formats the changelog for display.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if !self.sections.is_empty() {
-            f.write_str("## Changelog")?;
-        }
-
-        // Write all sections
-        for section in self.sections.values() {
-            f.write_str("\n\n")?;
-            section.fmt(f)?;
-        }
-
-        // No errors occurred
-        Ok(())
+        Markdown.render(f, self).map_err(|_| fmt::Error)
     }
 }
@@ -0,0 +1,93 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Contributor.
+
+use serde::Serialize;
+
+use ctrl_z_repository::commit::Signature;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Contributor.
+///
+/// Contributors are the authors and co-authors of the revisions that make up
+/// a changelog, which are identified by their email address, so the same
+/// person is only credited once, even when using different names.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Contributor {
+    /// Name.
+    name: String,
+    /// Email address.
+    email: String,
+    /// Whether this is the first contribution.
+    pub(super) first: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+#[allow(clippy::must_use_candidate)]
+impl Contributor {
+    /// Returns a reference to the name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns a reference to the email address.
+    #[inline]
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    /// Returns whether this is the first contribution.
+    ///
+    /// This is only determined when known contributors are passed to the
+    /// changelog, and is `false` otherwise.
+    #[inline]
+    pub fn is_first(&self) -> bool {
+        self.first
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl From<&Signature> for Contributor {
+    /// Creates a contributor from a signature.
+    #[inline]
+    fn from(signature: &Signature) -> Self {
+        Self {
+            name: signature.name().to_string(),
+            email: signature.email().to_string(),
+            first: false,
+        }
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Changelog renderer.
+
+use std::fmt;
+
+use super::Changelog;
+use crate::changeset::error::Result;
+
+mod html;
+mod json;
+mod keep_a_changelog;
+mod markdown;
+mod template;
+
+pub use html::Html;
+pub use json::Json;
+pub use keep_a_changelog::KeepAChangelog;
+pub use markdown::Markdown;
+pub use template::{Template, DEFAULT};
+
+// ----------------------------------------------------------------------------
+// Traits
+// ----------------------------------------------------------------------------
+
+/// Changelog renderer.
+///
+/// Renderers turn a [`Changelog`] into a specific output format, so the same
+/// data can be published to different targets, e.g., to GitHub releases and
+/// a documentation site. Built-in renderers are [`Markdown`], which is also
+/// used for [`fmt::Display`], [`KeepAChangelog`], [`Json`] and [`Html`], as
+/// well as [`Template`] for user-defined templates.
+pub trait Renderer {
+    /// Renders the changelog into the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Format`][] if writing fails, as well as
+    /// [`Error::Json`][] or [`Error::Template`][] if the changelog can't be
+    /// serialized or the template can't be rendered.
+    ///
+    /// [`Error::Format`]: crate::changeset::Error::Format
+    /// [`Error::Json`]: crate::changeset::Error::Json
+    /// [`Error::Template`]: crate::changeset::Error::Template
+    fn render(&self, f: &mut dyn fmt::Write, changelog: &Changelog) -> Result;
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod fixture {
+    use std::error::Error;
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    use ctrl_z_project::{Cargo, Workspace};
+    use ctrl_z_repository::forge::{Forge, Kind};
+    use ctrl_z_repository::{Commit, Date, Repository};
+
+    use crate::changeset::changelog::Changelog;
+    use crate::changeset::Changeset;
+
+    use super::Renderer;
+
+    /// Test result.
+    type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+    /// Commits all changes in the given directory.
+    fn commit(root: &Path, message: &str) -> Result {
+        let status = Command::new("git")
+            .current_dir(root)
+            .args(["-c", "user.name=Alice", "-c", "user.email=a@b.c"])
+            .args(["-c", "commit.gpgsign=false"])
+            .args(["commit", "--quiet", "--no-verify", "--allow-empty"])
+            .args(["--message", message])
+            .status()?;
+        assert!(status.success());
+        Ok(())
+    }
+
+    /// Writes and commits a file in the given directory.
+    fn write(root: &Path, path: &str, message: &str) -> Result {
+        fs::write(root.join(path), message)?;
+        Command::new("git")
+            .current_dir(root)
+            .args(["add", "."])
+            .status()?;
+        commit(root, message)
+    }
+
+    /// Creates a workspace with commits covering scopes, issues, notes and
+    /// co-authors, and returns the temporary directory.
+    fn setup() -> Result<TempDir> {
+        let dir = TempDir::new()?;
+        let root = dir.path();
+        for (path, name) in [("core", "zeta"), ("util", "alpha")] {
+            let path = root.join("packages").join(path).join("src");
+            fs::create_dir_all(&path)?;
+            fs::write(
+                path.join("../Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n"),
+            )?;
+        }
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"packages/core\", \"packages/util\"]\n",
+        )?;
+
+        // Initialize repository and create commits
+        let status = Command::new("git")
+            .current_dir(root)
+            .args(["init", "--quiet"])
+            .status()?;
+        assert!(status.success());
+        write(root, "README.md", "chore: initial commit")?;
+        write(
+            root,
+            "packages/core/src/lib.rs",
+            "feature: add parser\n\nCloses #12 and #34",
+        )?;
+        write(
+            root,
+            "packages/util/src/lib.rs",
+            "fix: handle <empty> input\n\n\
+             Co-authored-by: Bob <bob@example.com>",
+        )?;
+        write(
+            root,
+            "README.md",
+            "feature!: remove legacy api\n\n\
+             BREAKING CHANGE: use the new api instead",
+        )?;
+        Ok(dir)
+    }
+
+    /// Renders the changelog built by the given function with each of the
+    /// given renderers, where the function receives a forge and the date of
+    /// the latest commit of the test workspace.
+    pub(super) fn changelog<const N: usize, F>(
+        renderers: [&dyn Renderer; N], f: F,
+    ) -> Result<[String; N]>
+    where
+        F: for<'a> Fn(Changelog<'a>, &'a Forge, Date) -> Changelog<'a>,
+    {
+        let dir = setup()?;
+        let root = dir.path();
+        let workspace = Workspace::<Cargo>::read(root.join("Cargo.toml"))?;
+        let repository = Repository::open(root)?;
+        let forge =
+            Forge::new(Some(Kind::GitHub), "https://github.com/acme/repo");
+
+        // Create changeset from all commits
+        let mut changeset = Changeset::new(&workspace)?;
+        changeset.extend(
+            repository
+                .commits(..)?
+                .collect::<std::result::Result<Vec<Commit>, _>>()?,
+        )?;
+
+        // Render changelog with all renderers
+        let date = repository.find("HEAD")?.date();
+        let changelog = f(changeset.to_changelog(), &forge, date);
+        let mut values = Vec::with_capacity(N);
+        for renderer in renderers {
+            values.push(changelog.render(renderer)?);
+        }
+        Ok(values.try_into().expect("invariant"))
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! HTML renderer.
+
+use std::fmt;
+
+use ctrl_z_repository::Forge;
+
+use crate::changeset::changelog::section::Item;
+use crate::changeset::changelog::Changelog;
+use crate::changeset::error::Result;
+
+use super::Renderer;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// HTML renderer.
+///
+/// This renderer produces an HTML fragment without any styling, which is
+/// intended to be embedded into a documentation site. All text is escaped,
+/// so the fragment can be safely inserted into any page.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Html;
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Renderer for Html {
+    /// Renders the changelog into the given writer.
+    fn render(&self, f: &mut dyn fmt::Write, changelog: &Changelog) -> Result {
+        match changelog.version() {
+            Some(version) => match changelog.date() {
+                Some(date) => write!(f, "<h2>{version} – {date}</h2>")?,
+                None => write!(f, "<h2>{version}</h2>")?,
+            },
+            None => f.write_str("<h2>Changelog</h2>")?,
+        }
+
+        // Write version summary, if any, with one paragraph per block
+        if let Some(summary) = changelog.summary() {
+            for block in summary.split("\n\n").map(str::trim) {
+                if !block.is_empty() {
+                    write!(f, "\n<p>{}</p>", escape(block))?;
+                }
+            }
+        }
+
+        // Write all sections, each with a list of items
+        for section in changelog.sections() {
+            write!(f, "\n<h3>{}</h3>\n<ul>", escape(section.title()))?;
+            for item in section.items() {
+                f.write_str("\n<li>")?;
+                write_item(f, item, changelog.forge())?;
+                f.write_str("</li>")?;
+            }
+            f.write_str("\n</ul>")?;
+        }
+
+        // Write contributors, if any
+        if !changelog.contributors().is_empty() {
+            f.write_str("\n<h3>Contributors</h3>\n<ul>")?;
+            for contributor in changelog.contributors() {
+                write!(f, "\n<li>{}", escape(contributor.name()))?;
+                if contributor.is_first() {
+                    f.write_str(" (first contribution)")?;
+                }
+                f.write_str("</li>")?;
+            }
+            f.write_str("\n</ul>")?;
+        }
+
+        // Write link to full diff, if available
+        if let Some(url) = changelog.compare() {
+            write!(f, "\n<p><a href=\"{}\">Full diff</a></p>", escape(&url))?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Writes an item, including its commit, scopes, summary and issues, which
+/// are rendered as links, if a forge is given.
+fn write_item(
+    f: &mut dyn fmt::Write, item: &Item, forge: Option<&Forge>,
+) -> fmt::Result {
+    let revision = item.revision();
+    let id = revision.commit().id();
+    match forge {
+        Some(forge) => {
+            let url = escape(&forge.commit(&id));
+            write!(f, "<a href=\"{url}\"><code>{}</code></a>", id.short())?;
+        }
+        None => write!(f, "<code>{}</code>", id.short())?,
+    }
+
+    // Write affected scopes
+    for scope in item.scopes() {
+        write!(f, " <strong>{}</strong>", escape(scope))?;
+    }
+
+    // Write summary and relevant issues
+    write!(f, " – {}", escape(revision.change().summary()))?;
+    if !item.issues().is_empty() {
+        let issues = item.issues().iter().map(|&issue| match forge {
+            Some(forge) => {
+                let url = escape(&forge.issue(issue));
+                format!("<a href=\"{url}\">#{issue}</a>")
+            }
+            None => format!("#{issue}"),
+        });
+        write!(f, " ({})", issues.collect::<Vec<_>>().join(", "))?;
+    }
+
+    // Write migration note of breaking change, if any
+    if let Some(note) = revision.note() {
+        write!(f, "<blockquote>{}</blockquote>", escape(note))?;
+    }
+
+    // No errors occurred
+    Ok(())
+}
+
+/// Escapes the given text for use in HTML.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod render {
+        use semver::Version;
+        use std::error::Error;
+
+        use crate::changeset::changelog::renderer::fixture::changelog;
+        use crate::changeset::changelog::renderer::Html;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        #[test]
+        fn handles_plain() -> Result {
+            let [value] = changelog([&Html], |changelog, _, _| changelog)?;
+            assert!(value.starts_with("<h2>Changelog</h2>\n<h3>Breaking"));
+            assert!(value.contains(
+                "<strong>zeta</strong> – add parser \
+                                    (#12, #34)</li>"
+            ));
+            assert!(value
+                .contains("<blockquote>use the new api instead</blockquote>"));
+            assert!(!value.contains("<a href"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_escaping() -> Result {
+            let [value] = changelog([&Html], |changelog, _, _| {
+                changelog.with_summary("A & B.\n\n<b>C</b>")
+            })?;
+            assert!(value.contains("<p>A &amp; B.</p>\n<p>&lt;b&gt;C"));
+            assert!(value.contains("– handle &lt;empty&gt; input</li>"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_forge_and_version() -> Result {
+            let [value] = changelog([&Html], |changelog, forge, date| {
+                changelog
+                    .with_forge(forge)
+                    .with_range("v1.0.0", "v1.1.0")
+                    .with_version(Version::new(1, 1, 0))
+                    .with_date(date)
+                    .with_known_contributors(["a@b.c"])
+            })?;
+            assert!(value.starts_with("<h2>1.1.0 – "));
+            assert!(value.contains(
+                "<a href=\"https://github.com/acme/repo/issues/12\">#12</a>"
+            ));
+            assert!(value
+                .contains("<li>Alice</li>\n<li>Bob (first contribution)</li>"));
+            assert!(value.ends_with(
+                "<p><a href=\"https://github.com/acme/repo/compare/\
+                 v1.0.0...v1.1.0\">Full diff</a></p>"
+            ));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! JSON renderer.
+
+use std::fmt;
+
+use crate::changeset::changelog::Changelog;
+use crate::changeset::error::Result;
+
+use super::Renderer;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// JSON renderer.
+///
+/// This renderer produces pretty-printed JSON, including the version and its
+/// summary, as well as all sections and their items, which is intended to be
+/// consumed by scripts and other tools.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Json;
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Renderer for Json {
+    /// Renders the changelog into the given writer.
+    fn render(&self, f: &mut dyn fmt::Write, changelog: &Changelog) -> Result {
+        let value = serde_json::to_string_pretty(changelog)?;
+        f.write_str(&value)?;
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod render {
+        use semver::Version;
+        use serde_json::{json, Value};
+        use std::error::Error;
+
+        use crate::changeset::changelog::renderer::fixture::changelog;
+        use crate::changeset::changelog::renderer::Json;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        #[test]
+        fn handles_plain() -> Result {
+            let [value] = changelog([&Json], |changelog, _, _| changelog)?;
+            let value = serde_json::from_str::<Value>(&value)?;
+            assert_eq!(value["version"], Value::Null);
+            assert_eq!(value["compare"], Value::Null);
+
+            // Sections must be ordered, and items must not link to the forge
+            let sections = value["sections"].as_array().expect("invariant");
+            let categories =
+                sections.iter().map(|section| &section["category"]);
+            assert_eq!(
+                categories.collect::<Vec<_>>(),
+                [&json!("breaking"), &json!("feature"), &json!("fix")]
+            );
+            assert_eq!(sections[0]["items"][0]["url"], Value::Null);
+            assert_eq!(
+                sections[0]["items"][0]["note"],
+                json!("use the new api instead")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_forge_and_version() -> Result {
+            let [value] = changelog([&Json], |changelog, forge, date| {
+                changelog
+                    .with_forge(forge)
+                    .with_range("v1.0.0", "v1.1.0")
+                    .with_version(Version::new(1, 1, 0))
+                    .with_date(date)
+                    .with_summary("Summary.")
+            })?;
+            let value = serde_json::from_str::<Value>(&value)?;
+            assert_eq!(value["version"], json!("1.1.0"));
+            assert_eq!(value["summary"], json!("Summary."));
+            assert_eq!(
+                value["range"],
+                json!({ "from": "v1.0.0", "to": "v1.1.0" })
+            );
+            assert_eq!(
+                value["compare"],
+                json!("https://github.com/acme/repo/compare/v1.0.0...v1.1.0")
+            );
+
+            // Issues of items must link to the forge
+            let item = &value["sections"][1]["items"][0];
+            assert_eq!(item["issues"], json!([12, 34]));
+            assert_eq!(
+                item["issue_urls"][0],
+                json!("https://github.com/acme/repo/issues/12")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_known_contributors() -> Result {
+            let [value] = changelog([&Json], |changelog, _, _| {
+                changelog.with_known_contributors(["a@b.c"])
+            })?;
+            let value = serde_json::from_str::<Value>(&value)?;
+            assert_eq!(
+                value["contributors"],
+                json!([
+                    { "name": "Alice", "email": "a@b.c", "first": false },
+                    { "name": "Bob", "email": "bob@example.com", "first": true }
+                ])
+            );
+            Ok(())
+        }
+    }
+}
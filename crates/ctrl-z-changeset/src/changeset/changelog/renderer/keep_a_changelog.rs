@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Keep a Changelog renderer.
+
+use std::fmt;
+
+use ctrl_z_repository::Forge;
+
+use crate::changeset::change::Kind;
+use crate::changeset::changelog::section::Item;
+use crate::changeset::changelog::Changelog;
+use crate::changeset::error::Result;
+
+use super::Renderer;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Section titles in the order mandated by Keep a Changelog.
+const TITLES: [&str; 3] = ["Added", "Changed", "Fixed"];
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Keep a Changelog renderer.
+///
+/// This renderer produces Markdown following the conventions of [Keep a
+/// Changelog], which is intended for changelog files. Items are regrouped by
+/// their kind of change into the sections `Added`, `Changed` and `Fixed`,
+/// and breaking changes are marked as such in place, since Keep a Changelog
+/// doesn't define a dedicated section for them.
+///
+/// [Keep a Changelog]: https://keepachangelog.com
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeepAChangelog;
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Renderer for KeepAChangelog {
+    /// Renders the changelog into the given writer.
+    fn render(&self, f: &mut dyn fmt::Write, changelog: &Changelog) -> Result {
+        match changelog.version() {
+            Some(version) => write!(f, "## [{version}]")?,
+            None => f.write_str("## [Unreleased]")?,
+        }
+        if let Some(date) = changelog.date() {
+            write!(f, " - {date}")?;
+        }
+
+        // Write version summary, if any
+        if let Some(summary) = changelog.summary() {
+            f.write_str("\n\n")?;
+            f.write_str(summary)?;
+        }
+
+        // Regroup items by their kind of change, retaining the order of the
+        // sections, so breaking changes still come first in each group
+        let mut groups: [Vec<&Item>; 3] = Default::default();
+        for section in changelog.sections() {
+            for item in section.items() {
+                let index = match item.revision().change().kind() {
+                    Kind::Feature => 0,
+                    Kind::Fix => 2,
+                    _ => 1,
+                };
+                groups[index].push(item);
+            }
+        }
+
+        // Write all non-empty groups
+        for (title, items) in TITLES.iter().zip(&groups) {
+            if items.is_empty() {
+                continue;
+            }
+
+            // Write group title and items, each on a new line
+            write!(f, "\n\n### {title}\n")?;
+            for item in items {
+                f.write_str("\n- ")?;
+                write_item(f, item, changelog.forge())?;
+            }
+        }
+
+        // Write link reference for the version heading, if available
+        if let (Some(version), Some(url)) =
+            (changelog.version(), changelog.compare())
+        {
+            write!(f, "\n\n[{version}]: {url}")?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Writes an item, prefixed with its scopes and marked if breaking, linking
+/// issues, if a forge is given.
+fn write_item(
+    f: &mut dyn fmt::Write, item: &Item, forge: Option<&Forge>,
+) -> fmt::Result {
+    let revision = item.revision();
+    if revision.change().is_breaking() {
+        f.write_str("**Breaking:** ")?;
+    }
+
+    // Write affected scopes, followed by the summary
+    if !item.scopes().is_empty() {
+        write!(f, "**{}**: ", item.scopes().join(", "))?;
+    }
+    f.write_str(revision.change().summary())?;
+
+    // Write relevant issues
+    if !item.issues().is_empty() {
+        let issues = item.issues().iter().map(|&issue| match forge {
+            Some(forge) => format!("[#{issue}]({})", forge.issue(issue)),
+            None => format!("#{issue}"),
+        });
+        write!(f, " ({})", issues.collect::<Vec<_>>().join(", "))?;
+    }
+
+    // Write migration note of breaking change, if any
+    if let Some(note) = revision.note() {
+        write!(f, "\n  > {note}")?;
+    }
+
+    // No errors occurred
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod render {
+        use semver::Version;
+        use std::error::Error;
+
+        use crate::changeset::changelog::renderer::fixture::changelog;
+        use crate::changeset::changelog::renderer::KeepAChangelog;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        #[test]
+        fn handles_plain() -> Result {
+            let [value] =
+                changelog([&KeepAChangelog], |changelog, _, _| changelog)?;
+            assert_eq!(
+                value,
+                "## [Unreleased]\n\n\
+                 ### Added\n\n\
+                 - **Breaking:** remove legacy api\n  \
+                 > use the new api instead\n\
+                 - **zeta**: add parser (#12, #34)\n\n\
+                 ### Fixed\n\n\
+                 - **alpha**: handle <empty> input"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_forge_and_version() -> Result {
+            let [value] =
+                changelog([&KeepAChangelog], |changelog, forge, _| {
+                    changelog
+                        .with_forge(forge)
+                        .with_range("v1.0.0", "v1.1.0")
+                        .with_version(Version::new(1, 1, 0))
+                        .with_summary("Summary.")
+                })?;
+            assert!(value.starts_with("## [1.1.0]\n\nSummary.\n\n### Added"));
+            assert!(value.contains(
+                "([#12](https://github.com/acme/repo/issues/12), \
+                 [#34](https://github.com/acme/repo/issues/34))"
+            ));
+            assert!(value.ends_with(
+                "\n\n[1.1.0]: https://github.com/acme/repo/compare/\
+                 v1.0.0...v1.1.0"
+            ));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_date() -> Result {
+            let [value] =
+                changelog([&KeepAChangelog], |changelog, _, date| {
+                    changelog
+                        .with_version(Version::new(1, 1, 0))
+                        .with_date(date)
+                })?;
+            assert!(value.starts_with("## [1.1.0] - "));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Markdown renderer.
+
+use std::fmt;
+
+use crate::changeset::changelog::Changelog;
+use crate::changeset::error::Result;
+
+use super::Renderer;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Markdown renderer.
+///
+/// This renderer produces Markdown as supported by GitHub, which is intended
+/// for release notes, and starts with the version summary, if any, followed
+/// by a heading, the sections of the changelog and its contributors. If the
+/// version and release date are set, the heading includes both, e.g.,
+/// `## 1.2.0 – 2026-10-17`, so the output can be used in a changelog file.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Markdown;
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Renderer for Markdown {
+    /// Renders the changelog into the given writer.
+    fn render(&self, f: &mut dyn fmt::Write, changelog: &Changelog) -> Result {
+        // Write heading with version and release date, if both are given, or
+        // a generic heading, unless there are no sections
+        let heading = match (changelog.version(), changelog.date()) {
+            (Some(version), Some(date)) => {
+                Some(format!("## {version} – {date}"))
+            }
+            _ => (!changelog.is_empty()).then(|| String::from("## Changelog")),
+        };
+        if let Some(summary) = changelog.summary() {
+            f.write_str(summary)?;
+            if heading.is_some() {
+                f.write_str("\n\n")?;
+            }
+        }
+        if let Some(heading) = heading {
+            f.write_str(&heading)?;
+        }
+
+        // Write all sections, linking to the forge, if any
+        for section in changelog.sections() {
+            f.write_str("\n\n")?;
+            section.render(f, changelog.forge())?;
+        }
+
+        // Write contributors, unless there are no sections
+        if !changelog.is_empty() && !changelog.contributors().is_empty() {
+            f.write_str("\n\n### Contributors\n")?;
+            for contributor in changelog.contributors() {
+                write!(f, "\n- {}", contributor.name())?;
+                if contributor.is_first() {
+                    f.write_str(" (first contribution)")?;
+                }
+            }
+        }
+
+        // Write link to full diff, if available
+        if let (Some((from, to)), Some(url)) =
+            (changelog.range(), changelog.compare())
+        {
+            write!(f, "\n\n**Full diff**: [{from}...{to}]({url})")?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
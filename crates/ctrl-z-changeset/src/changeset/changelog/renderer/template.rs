@@ -0,0 +1,236 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Template renderer.
+
+use minijinja::Environment;
+use std::fmt;
+
+use crate::changeset::changelog::Changelog;
+use crate::changeset::error::Result;
+
+use super::Renderer;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Default template, reproducing the output of the [`Markdown`][] renderer.
+///
+/// [`Markdown`]: super::Markdown
+pub const DEFAULT: &str = include_str!("template/default.md.jinja");
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Template renderer.
+///
+/// This renderer passes the changelog to a user-defined template, so release
+/// notes can be shaped freely. Templates use the [MiniJinja][] syntax, and
+/// receive the same data as the [`Json`][] renderer, i.e., `version`, `date`,
+/// `summary`, `range` with `from` and `to`, and `compare`, as well as
+/// `sections` with a `title` and `items`, each of which provide `id`, `url`,
+/// `author`, `date`, `kind`, `summary`, `breaking`, `scopes`, `issues`,
+/// `issue_urls` and `note`, and `contributors` with a `name`, `email` and
+/// `first` flag. Links are only set if a forge is configured.
+///
+/// [MiniJinja]: https://docs.rs/minijinja
+/// [`Json`]: super::Json
+#[derive(Clone, Debug)]
+pub struct Template {
+    /// Template source.
+    source: String,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Template {
+    /// Creates a template renderer from the given source.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Template`][] if the template is invalid.
+    ///
+    /// [`Error::Template`]: crate::changeset::Error::Template
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ctrl_z_changeset::changelog::renderer::Template;
+    ///
+    /// // Create template renderer
+    /// let template = Template::new("{{ version }}")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<S>(source: S) -> Result<Self>
+    where
+        S: Into<String>,
+    {
+        let source = source.into();
+
+        // Compile template once, so syntax errors are reported early
+        Environment::new().template_from_str(&source)?;
+        Ok(Self { source })
+    }
+
+    /// Renders the changelog with the template.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Template`][] if rendering fails.
+    ///
+    /// [`Error::Template`]: crate::changeset::Error::Template
+    pub fn render(&self, changelog: &Changelog) -> Result<String> {
+        let env = Environment::new();
+        let template = env.template_from_str(&self.source)?;
+        Ok(template.render(changelog)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Renderer for Template {
+    /// Renders the changelog into the given writer.
+    fn render(&self, f: &mut dyn fmt::Write, changelog: &Changelog) -> Result {
+        let value = Template::render(self, changelog)?;
+        f.write_str(&value)?;
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Default for Template {
+    /// Creates the default template renderer.
+    #[inline]
+    fn default() -> Self {
+        Self { source: String::from(DEFAULT) }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod render {
+        use semver::Version;
+        use std::error::Error;
+
+        use ctrl_z_repository::{Date, Forge};
+
+        use crate::changeset::changelog::renderer::fixture::changelog;
+        use crate::changeset::changelog::renderer::{Markdown, Template};
+        use crate::changeset::changelog::Changelog;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Asserts that the default template reproduces the output of the
+        /// Markdown renderer for the changelog built by the given function,
+        /// which receives a forge and the date of the latest commit.
+        fn assert_same<F>(f: F) -> Result<String>
+        where
+            F: for<'a> Fn(Changelog<'a>, &'a Forge, Date) -> Changelog<'a>,
+        {
+            let [value, template] =
+                changelog([&Markdown, &Template::default()], f)?;
+            assert_eq!(template, value);
+            Ok(value)
+        }
+
+        #[test]
+        fn handles_plain() -> Result {
+            assert_same(|changelog, _, _| changelog)?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_summary() -> Result {
+            assert_same(|changelog, _, _| changelog.with_summary("Summary."))?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_forge() -> Result {
+            assert_same(|changelog, forge, _| {
+                changelog
+                    .with_summary("Summary.")
+                    .with_forge(forge)
+                    .with_range("v1.0.0", "v1.1.0")
+            })?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_forge_without_range() -> Result {
+            assert_same(|changelog, forge, _| changelog.with_forge(forge))?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_known_contributors() -> Result {
+            assert_same(|changelog, _, _| {
+                changelog.with_known_contributors(["a@b.c"])
+            })?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_version_and_date() -> Result {
+            let value = assert_same(|changelog, _, date| {
+                changelog
+                    .with_version(Version::new(1, 1, 0))
+                    .with_date(date)
+            })?;
+            assert!(value.starts_with("## 1.1.0 – "));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_version_and_date_with_summary() -> Result {
+            let value = assert_same(|changelog, _, date| {
+                changelog
+                    .with_summary("Summary.")
+                    .with_version(Version::new(1, 1, 0))
+                    .with_date(date)
+            })?;
+            assert!(value.starts_with("Summary.\n\n## 1.1.0 – "));
+            Ok(())
+        }
+    }
+}
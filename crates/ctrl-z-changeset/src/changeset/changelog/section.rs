@@ -25,7 +25,10 @@
 
 //! Section.
 
-use std::fmt::{self, Write};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+
+use ctrl_z_repository::Forge;
 
 mod category;
 mod item;
@@ -42,16 +45,55 @@ pub use item::Item;
 pub struct Section<'a> {
     /// Section category.
     category: Category,
+    /// Section title.
+    title: &'a str,
     /// Section items.
     items: Vec<Item<'a>>,
 }
 
+/// Section or item linking to a forge, if any, for serialization.
+///
+/// Sections and items don't know about the forge, which is set on the
+/// changelog, so the changelog wraps them when serializing, which adds the
+/// URLs of commits and issues, e.g., for use in templates.
+pub(crate) struct Linked<'a, T>(pub &'a T, pub Option<&'a Forge>);
+
 // ----------------------------------------------------------------------------
 // Implementations
 // ----------------------------------------------------------------------------
 
+impl<'a> Section<'a> {
+    /// Creates a section with the given category and title.
+    #[must_use]
+    pub fn new(category: Category, title: &'a str) -> Self {
+        Self {
+            category,
+            title,
+            items: Vec::new(),
+        }
+    }
+}
+
 #[allow(clippy::must_use_candidate)]
-impl Section<'_> {
+impl<'a> Section<'a> {
+    /// Returns the section category.
+    #[inline]
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// Returns the section title.
+    #[inline]
+    pub fn title(&self) -> &'a str {
+        self.title
+    }
+
+    /// Returns a reference to the section items.
+    #[inline]
+    pub fn items(&self) -> &[Item<'a>] {
+        &self.items
+    }
+
     /// Returns the number of items.
     #[inline]
     pub fn len(&self) -> usize {
@@ -65,15 +107,61 @@ impl Section<'_> {
     }
 }
 
+impl Section<'_> {
+    /// Renders the section in Markdown into the given writer.
+    ///
+    /// If a forge is given, items link to it - see [`Item::render`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`fmt::Error`] if writing fails.
+    pub fn render(
+        &self, f: &mut dyn fmt::Write, forge: Option<&Forge>,
+    ) -> fmt::Result {
+        f.write_str("### ")?;
+        f.write_str(self.title)?;
+        f.write_char('\n')?;
+
+        // Write all items, each on a new line
+        for item in &self.items {
+            f.write_char('\n')?;
+            f.write_str("- ")?;
+            item.render(f, forge)?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl From<Category> for Section<'_> {
-    /// Creates a section from a category.
+impl Serialize for Section<'_> {
+    /// Serializes the section.
     #[inline]
-    fn from(category: Category) -> Self {
-        Self { category, items: Vec::new() }
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Linked(self, None).serialize(serializer)
+    }
+}
+
+impl Serialize for Linked<'_, Section<'_>> {
+    /// Serializes the section with links to the forge.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Linked(section, forge) = *self;
+        let items = section.items.iter().map(|item| Linked(item, forge));
+        let mut state = serializer.serialize_struct("Section", 3)?;
+        state.serialize_field("category", &section.category)?;
+        state.serialize_field("title", section.title)?;
+        state.serialize_field("items", &items.collect::<Vec<_>>())?;
+        state.end()
     }
 }
 
@@ -81,19 +169,8 @@ impl From<Category> for Section<'_> {
 
 impl fmt::Display for Section<'_> {
     /// Formats the section for display.
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str("### ")?;
-        self.category.fmt(f)?;
-        f.write_char('\n')?;
-
-        // Write all items, each on a new line
-        for item in &self.items {
-            f.write_char('\n')?;
-            f.write_str("- ")?;
-            item.fmt(f)?;
-        }
-
-        // No errors occurred
-        Ok(())
+        self.render(f, None)
     }
 }
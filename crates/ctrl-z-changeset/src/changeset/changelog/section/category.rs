@@ -25,6 +25,7 @@
 
 //! Section category.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::changeset::change::Kind;
@@ -35,7 +36,10 @@ use crate::changeset::Change;
 // ----------------------------------------------------------------------------
 
 /// Section category.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum Category {
     /// Breaking changes.
     Breaking,
@@ -49,6 +53,24 @@ pub enum Category {
     Refactor,
 }
 
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Category {
+    /// Returns the default title of the section category.
+    #[must_use]
+    pub fn title(self) -> &'static str {
+        match self {
+            Category::Breaking => "Breaking changes",
+            Category::Feature => "Features",
+            Category::Fix => "Bug fixes",
+            Category::Performance => "Performance improvements",
+            Category::Refactor => "Refactorings",
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -84,12 +106,6 @@ impl From<&Change> for Option<Category> {
 impl fmt::Display for Category {
     /// Formats the section category for display.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Category::Breaking => f.write_str("Breaking changes"),
-            Category::Feature => f.write_str("Features"),
-            Category::Fix => f.write_str("Bug fixes"),
-            Category::Performance => f.write_str("Performance improvements"),
-            Category::Refactor => f.write_str("Refactorings"),
-        }
+        f.write_str(self.title())
     }
 }
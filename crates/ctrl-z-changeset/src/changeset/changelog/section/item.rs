@@ -25,12 +25,15 @@
 
 //! Section item.
 
-use std::fmt::{self, Write};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+
+use ctrl_z_repository::Forge;
 
 use crate::changeset::revision::Revision;
 use crate::changeset::scopes::Scopes;
 
-use super::Section;
+use super::{Linked, Section};
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -70,15 +73,45 @@ impl<'a> Section<'a> {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Trait implementations
-// ----------------------------------------------------------------------------
+#[allow(clippy::must_use_candidate)]
+impl<'a> Item<'a> {
+    /// Returns a reference to the revision.
+    #[inline]
+    pub fn revision(&self) -> &'a Revision<'a> {
+        self.revision
+    }
 
-impl fmt::Display for Item<'_> {
-    /// Formats the section item for display.
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    /// Returns a reference to the names of affected scopes.
+    #[inline]
+    pub fn scopes(&self) -> &[&'a str] {
+        &self.scopes
+    }
+
+    /// Returns a reference to the relevant issues.
+    #[inline]
+    pub fn issues(&self) -> &[u32] {
+        &self.issues
+    }
+}
+
+impl Item<'_> {
+    /// Renders the section item in Markdown into the given writer.
+    ///
+    /// If a forge is given, commit identifiers and issues are rendered as
+    /// links to the forge, which is what [`fmt::Display`] omits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`fmt::Error`] if writing fails.
+    pub fn render(
+        &self, f: &mut dyn fmt::Write, forge: Option<&Forge>,
+    ) -> fmt::Result {
         let id = self.revision.commit().id();
-        id.short().fmt(f)?;
+        if let Some(forge) = forge {
+            write!(f, "[{}]({})", id.short(), forge.commit(&id))?;
+        } else {
+            f.write_str(&id.short())?;
+        }
 
         // Write affected scopes
         if !self.scopes.is_empty() {
@@ -103,9 +136,13 @@ impl fmt::Display for Item<'_> {
         // Write relevant issues
         if !self.issues.is_empty() {
             f.write_str(" (")?;
-            for (i, issue) in self.issues.iter().enumerate() {
-                f.write_char('#')?;
-                issue.fmt(f)?;
+            for (i, &issue) in self.issues.iter().enumerate() {
+                match forge {
+                    Some(forge) => {
+                        write!(f, "[#{issue}]({})", forge.issue(issue))?;
+                    }
+                    None => write!(f, "#{issue}")?,
+                }
 
                 // Write comma if not last
                 if i < self.issues.len() - 1 {
@@ -115,7 +152,78 @@ impl fmt::Display for Item<'_> {
             f.write_char(')')?;
         }
 
+        // Write migration note of breaking change, if any, as a quote that is
+        // indented, so it's rendered as part of the list item
+        if let Some(note) = self.revision.note() {
+            f.write_str("\n  > ")?;
+            f.write_str(note)?;
+        }
+
         // No errors occurred
         Ok(())
     }
 }
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Serialize for Item<'_> {
+    /// Serializes the section item.
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Linked(self, None).serialize(serializer)
+    }
+}
+
+impl Serialize for Linked<'_, Item<'_>> {
+    /// Serializes the section item with links to the forge.
+    ///
+    /// The URLs of the commit and issues are only included if a forge is set,
+    /// and the URLs of issues are given in the same order as the issues.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Linked(item, forge) = *self;
+        let commit = item.revision.commit();
+        let change = item.revision.change();
+
+        // Obtain URLs of commit and issues from the forge, if any
+        let url = forge.map(|forge| forge.commit(&commit.id()));
+        let issue_urls = forge.map_or_else(Vec::new, |forge| {
+            item.issues
+                .iter()
+                .map(|&issue| forge.issue(issue))
+                .collect()
+        });
+
+        // Serialize section item
+        let mut state = serializer.serialize_struct("Item", 11)?;
+        state.serialize_field("id", &commit.id().to_string())?;
+        state.serialize_field("url", &url)?;
+        state.serialize_field("author", commit.author().name())?;
+        state.serialize_field("date", &commit.date().to_string())?;
+        state.serialize_field("kind", &change.kind())?;
+        state.serialize_field("summary", change.summary())?;
+        state.serialize_field("breaking", &change.is_breaking())?;
+        state.serialize_field("scopes", &item.scopes)?;
+        state.serialize_field("issues", &item.issues)?;
+        state.serialize_field("issue_urls", &issue_urls)?;
+        state.serialize_field("note", &item.revision.note())?;
+        state.end()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Item<'_> {
+    /// Formats the section item for display.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.render(f, None)
+    }
+}
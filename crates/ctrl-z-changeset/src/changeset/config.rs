@@ -0,0 +1,188 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Changeset configuration.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use ctrl_z_project::version::Increment;
+
+use super::change::{self, Change, Kind};
+use super::changelog::Category;
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Change kind setting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Setting {
+    /// Change kind leads to a major version increment.
+    Major,
+    /// Change kind leads to a minor version increment.
+    Minor,
+    /// Change kind leads to a patch version increment.
+    Patch,
+    /// Change kind doesn't lead to a version increment.
+    None,
+    /// Change kind is not allowed.
+    Disabled,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Scope mode.
+///
+/// The scope mode determines how the explicit scope of a change, e.g., `cli`
+/// in `fix(cli): summary`, is combined with the scopes derived from the paths
+/// that are touched by the commit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Explicit scope is added to the derived scopes.
+    #[default]
+    Augment,
+    /// Explicit scope replaces the derived scopes.
+    Override,
+}
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Changeset configuration.
+///
+/// The configuration allows to override the version increment for each kind
+/// of change, to disallow certain kinds of changes, and to override the titles
+/// of changelog sections. Everything that is not overridden keeps its default,
+/// as implemented by [`Change::as_increment`] and [`Category`].
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// Change kind settings.
+    kinds: BTreeMap<Kind, Setting>,
+    /// Section category titles.
+    categories: BTreeMap<Category, String>,
+    /// Scope mode.
+    mode: Mode,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Config {
+    /// Creates a changeset configuration.
+    #[must_use]
+    pub fn new(
+        kinds: BTreeMap<Kind, Setting>, categories: BTreeMap<Category, String>,
+    ) -> Self {
+        Self {
+            kinds,
+            categories,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the scope mode.
+    #[must_use]
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Attempts to create a change from a string.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Change::from_str`], as well as
+    /// [`Error::Kind`][] if the kind of change is disabled.
+    ///
+    /// [`Error::Kind`]: crate::changeset::change::Error::Kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// use ctrl_z_changeset::change::Kind;
+    /// use ctrl_z_changeset::config::{Config, Setting};
+    ///
+    /// // Create configuration disallowing chores
+    /// let kinds = BTreeMap::from([(Kind::Chore, Setting::Disabled)]);
+    /// let config = Config::new(kinds, BTreeMap::new());
+    /// assert!(config.parse("fix: summary").is_ok());
+    /// assert!(config.parse("chore: summary").is_err());
+    /// ```
+    pub fn parse(&self, value: &str) -> change::Result<Change> {
+        let change = Change::from_str(value)?;
+        if self.kinds.get(&change.kind()) == Some(&Setting::Disabled) {
+            Err(change::Error::Kind)
+        } else {
+            Ok(change)
+        }
+    }
+
+    /// Returns the version increment for the given change.
+    ///
+    /// Breaking changes always lead to a major version increment, as long as
+    /// the kind of change leads to a version increment at all.
+    #[must_use]
+    pub fn increment(&self, change: &Change) -> Option<Increment> {
+        let increment = match self.kinds.get(&change.kind()) {
+            Some(Setting::Major) => Increment::Major,
+            Some(Setting::Minor) => Increment::Minor,
+            Some(Setting::Patch) => Increment::Patch,
+            Some(Setting::None | Setting::Disabled) => return None,
+            None => return change.as_increment(),
+        };
+
+        // If a version increment is determined, check for breaking changes,
+        // as they must always lead to a major version increment
+        if change.is_breaking() {
+            Some(Increment::Major)
+        } else {
+            Some(increment)
+        }
+    }
+
+    /// Returns the title for the given section category.
+    #[must_use]
+    pub fn title(&self, category: Category) -> &str {
+        self.categories
+            .get(&category)
+            .map_or_else(|| category.title(), String::as_str)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl Config {
+    /// Returns the scope mode.
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+}
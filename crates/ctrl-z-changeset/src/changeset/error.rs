@@ -25,7 +25,7 @@
 
 //! Changeset error.
 
-use std::result;
+use std::{fmt, result};
 use thiserror::Error;
 
 use ctrl_z_repository as repository;
@@ -54,6 +54,15 @@ pub enum Error {
     /// Missing summary.
     #[error("missing summary")]
     Summary,
+    /// Template error.
+    #[error(transparent)]
+    Template(#[from] minijinja::Error),
+    /// JSON error.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// Formatting error.
+    #[error(transparent)]
+    Format(#[from] fmt::Error),
 }
 
 // ----------------------------------------------------------------------------
@@ -27,11 +27,12 @@
 
 use std::cmp;
 use std::collections::BTreeSet;
-use std::str::FromStr;
 
+use ctrl_z_repository::commit::{parse_trailers, Signature};
 use ctrl_z_repository::Commit;
 
 use super::change::Change;
+use super::config::Mode;
 use super::error::Result;
 use super::Changeset;
 
@@ -50,6 +51,10 @@ pub struct Revision<'a> {
     scopes: Vec<usize>,
     /// Relevant issues.
     issues: Vec<u32>,
+    /// Migration note.
+    note: Option<String>,
+    /// Co-authors.
+    co_authors: Vec<Signature>,
 }
 
 // ----------------------------------------------------------------------------
@@ -81,6 +86,18 @@ impl Revision<'_> {
     pub fn issues(&self) -> &[u32] {
         &self.issues
     }
+
+    /// Returns the migration note of a breaking change, if any.
+    #[inline]
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns a reference to the co-authors.
+    #[inline]
+    pub fn co_authors(&self) -> &[Signature] {
+        &self.co_authors
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -90,23 +107,42 @@ impl<'a> Changeset<'a> {
     ///
     /// # Errors
     ///
-    /// This method returns [`Error::Repository`][] if the commit deltas can't
-    /// be retrieved. If the commit message couldn't be parsed, it will just be
-    /// ignored, since there are several types of commits that will not make it
-    /// into the changeset, e.g., merge commits.
+    /// This method returns [`Error::Repository`][] if the commit deltas or the
+    /// trailers can't be retrieved. If the commit message couldn't be parsed,
+    /// it will just be ignored, since there are several types of commits that
+    /// will not make it into the changeset, e.g., merge commits.
     ///
     /// [`Error::Repository`]: crate::changeset::Error::Repository
     #[allow(clippy::missing_panics_doc)]
     pub fn add(&mut self, commit: Commit<'a>) -> Result {
-        if let Ok(change) = Change::from_str(commit.summary()) {
+        if let Ok(mut change) = self.config.parse(commit.summary()) {
+            // Check for a breaking change footer in the commit body, which has
+            // the same effect as an exclamation mark in the summary, but also
+            // provides a migration note to be rendered in the changelog
+            let note = commit.body().map(parse_note).transpose()?.flatten();
+            if note.is_some() {
+                change = change.into_breaking();
+            }
+
             // Retrieve affected scopes from commit
             let mut scopes = BTreeSet::new();
             for delta in commit.deltas()? {
                 scopes.extend(self.scopes.get(delta.path()));
             }
 
+            // If the change has an explicit scope that refers to a package, it
+            // augments or overrides the derived scopes, depending on the mode.
+            // Unknown scopes are ignored, as they can't be attributed.
+            let scope = change.scope().and_then(|name| self.scopes.find(name));
+            if let Some(index) = scope {
+                if self.config.mode() == Mode::Override {
+                    scopes.clear();
+                }
+                scopes.insert(index);
+            }
+
             // Update increments for affected scopes
-            let increment = change.as_increment();
+            let increment = self.config.increment(&change);
             for &index in &scopes {
                 self.increments[index] =
                     cmp::max(self.increments[index], increment);
@@ -116,12 +152,18 @@ impl<'a> Changeset<'a> {
             // by a hash sign followed by a number, e.g., "#123"
             let issues = commit.body().map(parse_issues).unwrap_or_default();
 
+            // Retrieve co-authors from commit, which are credited alongside
+            // the author in the contributors section of the changelog
+            let co_authors = commit.co_authors()?;
+
             // Create revision and add to changeset
             self.revisions.push(Revision {
                 commit,
                 change,
                 scopes: scopes.into_iter().collect(),
                 issues: issues.into_iter().collect(),
+                note,
+                co_authors,
             });
         }
 
@@ -153,12 +195,42 @@ impl<'a> Changeset<'a> {
         // No errors occurred
         Ok(())
     }
+
+    /// Retains only the revisions matching the given predicate.
+    ///
+    /// Version increments are recomputed from the retained revisions, so they
+    /// only reflect the changes that are still part of the changeset, e.g.,
+    /// when narrowing a changeset down to the revisions affecting a package.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Revision<'a>) -> bool,
+    {
+        self.revisions.retain(f);
+        self.increments.fill(None);
+
+        // Recompute version increments for affected scopes
+        for revision in &self.revisions {
+            let increment = self.config.increment(revision.change());
+            for &index in revision.scopes() {
+                self.increments[index] =
+                    cmp::max(self.increments[index], increment);
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Parses the migration note from the `BREAKING CHANGE` trailer in body.
+fn parse_note(body: &str) -> Result<Option<String>> {
+    let trailers = parse_trailers(body)?;
+    Ok(trailers
+        .into_iter()
+        .find_map(|(key, value)| (key == "BREAKING-CHANGE").then_some(value)))
+}
+
 /// Parses issue references, e.g., `#123` from body.
 fn parse_issues(body: &str) -> BTreeSet<u32> {
     body.split_whitespace()
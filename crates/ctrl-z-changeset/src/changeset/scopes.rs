@@ -105,6 +105,35 @@ impl Scopes {
             path.components().count()
         })
     }
+
+    /// Returns the scope with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ctrl_z_changeset::Scopes;
+    ///
+    /// // Create scope set builder and add path
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/ctrl-z", "ctrl-z")?;
+    ///
+    /// // Create scope set from builder
+    /// let scopes = builder.build()?;
+    ///
+    /// // Obtain scope by name
+    /// assert_eq!(scopes.find("ctrl-z"), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find<N>(&self, name: N) -> Option<usize>
+    where
+        N: AsRef<str>,
+    {
+        let name = name.as_ref();
+        self.paths.iter().position(|(_, scope)| scope == name)
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -146,3 +175,40 @@ impl fmt::Debug for Scopes {
             .finish_non_exhaustive()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod get {
+        use crate::changeset::scopes::{Result, Scopes};
+
+        #[test]
+        fn handles_insertion_order() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("packages/util", "alpha")?;
+            builder.add("packages/core", "zeta")?;
+            let scopes = builder.build()?;
+            assert_eq!(scopes.get("packages/util/src/lib.rs"), Some(0));
+            assert_eq!(scopes.get("packages/core/src/lib.rs"), Some(1));
+            assert_eq!(scopes.find("alpha"), Some(0));
+            assert_eq!(scopes.find("zeta"), Some(1));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_nested_scopes() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("packages/core/nested", "nested")?;
+            builder.add("packages/core", "core")?;
+            let scopes = builder.build()?;
+            assert_eq!(scopes.get("packages/core/nested/lib.rs"), Some(0));
+            assert_eq!(scopes.get("packages/core/lib.rs"), Some(1));
+            assert_eq!(scopes.get("packages/other/lib.rs"), None);
+            Ok(())
+        }
+    }
+}
@@ -26,7 +26,6 @@
 //! Scope set builder.
 
 use globset::{Glob, GlobSetBuilder};
-use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use super::error::{Error, Result};
@@ -39,8 +38,8 @@ use super::Scopes;
 /// Scope set builder.
 #[derive(Debug)]
 pub struct Builder {
-    /// Registered scopes.
-    paths: BTreeMap<PathBuf, String>,
+    /// Registered scopes, in the order they were added.
+    paths: Vec<(PathBuf, String)>,
     /// Glob set builder.
     globs: GlobSetBuilder,
 }
@@ -66,13 +65,17 @@ impl Builder {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            paths: BTreeMap::new(),
+            paths: Vec::new(),
             globs: GlobSetBuilder::new(),
         }
     }
 
     /// Adds a scope to the scope set.
     ///
+    /// Scopes are indexed in the order they were added, which is the order in
+    /// which the glob patterns are added as well, so indices of matches can be
+    /// used to look up scopes, and callers can rely on the order.
+    ///
     /// # Errors
     ///
     /// This method returns [`Error::Glob`] if the [`Glob`] can't be built.
@@ -101,7 +104,7 @@ impl Builder {
         }
 
         // Ensure path does not already exist, as scopes can't overlap
-        if self.paths.contains_key(path) {
+        if self.paths.iter().any(|(existing, _)| existing == path) {
             Err(Error::PathExists)
 
         // Create pattern matching all files under the given path
@@ -109,7 +112,7 @@ impl Builder {
             let glob = path.join("**");
 
             // Create glob and add to builder
-            self.paths.insert(path.to_path_buf(), name.into());
+            self.paths.push((path.to_path_buf(), name.into()));
             self.globs.add(Glob::new(&glob.to_string_lossy())?);
 
             // Return builder for chaining
@@ -143,7 +146,7 @@ impl Builder {
     /// ```
     pub fn build(self) -> Result<Scopes> {
         Ok(Scopes {
-            paths: self.paths.into_iter().collect(),
+            paths: self.paths,
             globs: self.globs.build()?,
         })
     }
@@ -31,6 +31,7 @@ mod changeset;
 
 pub use changeset::change::{self, Change};
 pub use changeset::changelog::{self, Changelog};
+pub use changeset::config::{self, Config};
 pub use changeset::revision::{self, Revision};
 pub use changeset::scopes::{self, Scopes};
 pub use changeset::{Changeset, Error, Result};
@@ -82,6 +82,12 @@ where
         })
     }
 
+    /// Returns a reference to the path.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     /// Returns a reference to the name.
     #[inline]
     pub fn name(&self) -> Option<&str> {
@@ -93,6 +99,12 @@ where
     pub fn version(&self) -> Option<&Version> {
         self.manifest.version()
     }
+
+    /// Creates an iterator over the dependencies.
+    #[inline]
+    pub fn dependencies(&self) -> impl Iterator<Item = &str> {
+        self.manifest.dependencies()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -56,6 +56,9 @@ pub enum Error {
     /// JSON error.
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+    /// Invalid increment.
+    #[error("invalid increment")]
+    Increment,
 }
 
 // ----------------------------------------------------------------------------
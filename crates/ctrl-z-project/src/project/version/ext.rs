@@ -26,6 +26,7 @@
 //! Version extensions.
 
 use semver::{BuildMetadata, Error, Prerelease, Version};
+use std::cmp;
 
 use super::increment::Increment;
 
@@ -38,6 +39,9 @@ pub trait VersionExt {
     /// Returns the next version after applying the given increment.
     fn bump(&self, increment: Increment) -> Version;
 
+    /// Returns the next pre-release version with the given label.
+    fn bump_pre(&self, increment: Increment, label: &Prerelease) -> Version;
+
     /// Returns the highest possible increment for the version.
     fn max_bump(&self) -> Increment;
 
@@ -73,21 +77,46 @@ impl VersionExt for Version {
     /// supported, so it's impossible to accidentally turn `0.0.z` into `0.1.0`.
     /// If that is desired, an explicit version bump is required.
     ///
+    /// Pre-release versions already denote the next version, which is why they
+    /// are promoted to it, unless the increment exceeds the one denoted by the
+    /// pre-release, e.g., `1.4.0-rc.1` is promoted to `1.4.0` for minor and
+    /// patch increments, but bumped to `2.0.0` for major increments.
+    ///
     /// # Examples
     ///
     /// ```
-    /// # use std::error::Error;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use ctrl_z_changeset::change::Kind;
-    ///
-    /// // Create change kind from string
-    /// let kind: Kind = "fix".parse()?;
-    /// # Ok(())
-    /// # }
+    /// use ctrl_z_project::version::{Increment, VersionExt};
+    /// use semver::Version;
+    ///
+    /// // Bump version
+    /// let version = Version::new(1, 3, 2);
+    /// assert_eq!(version.bump(Increment::Minor), Version::new(1, 4, 0));
+    ///
+    /// // Promote pre-release version
+    /// let version = Version::parse("1.4.0-rc.1").unwrap();
+    /// assert_eq!(version.bump(Increment::Patch), Version::new(1, 4, 0));
     /// ```
     fn bump(&self, increment: Increment) -> Version {
         let mut version = self.clone();
 
+        // Always reset pre-release identifier and build metadata
+        version.pre = Prerelease::EMPTY;
+        version.build = BuildMetadata::EMPTY;
+
+        // Determine the increment denoted by a pre-release version, which is
+        // the increment that led from the last stable version to it, and just
+        // promote the pre-release version if the increment doesn't exceed it
+        if !self.pre.is_empty() {
+            let denoted = match (self.major, self.minor, self.patch) {
+                (0, 0, _) | (_, _, 1..) => Increment::Patch,
+                (0, _, _) | (_, 1.., _) => Increment::Minor,
+                _ => Increment::Major,
+            };
+            if cmp::min(increment, self.max_bump()) <= denoted {
+                return version;
+            }
+        }
+
         // Apply increment according to semantic versioning rules
         match (self.major, self.minor, increment) {
             // 0.0.z -> 0.0.z+1
@@ -120,9 +149,72 @@ impl VersionExt for Version {
             }
         }
 
-        // Always reset pre-release identifier and build metadata
-        version.pre = Prerelease::EMPTY;
-        version.build = BuildMetadata::EMPTY;
+        // Return next version
+        version
+    }
+
+    /// Returns the next pre-release version with the given label.
+    ///
+    /// This method first applies the given increment via [`VersionExt::bump`],
+    /// and then appends the label together with a counter, which starts at 1.
+    /// If the version already is a pre-release of the next version with the
+    /// same label, the counter is incremented, e.g., `1.4.0-rc.1` is bumped to
+    /// `1.4.0-rc.2`. If the label is empty, no pre-release is appended.
+    ///
+    /// Switching to a label that orders before the current one, e.g., from
+    /// `1.4.0-rc.1` to `beta`, would yield a lower version, which is why the
+    /// increment is then applied to the promoted version, e.g., `1.4.1-beta.1`
+    /// for patch increments, so the result is always greater than the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ctrl_z_project::version::{Increment, VersionExt};
+    /// use semver::{Prerelease, Version};
+    ///
+    /// // Bump to pre-release version
+    /// let label = Prerelease::new("rc").unwrap();
+    /// let version = Version::new(1, 3, 2).bump_pre(Increment::Minor, &label);
+    /// assert_eq!(version, Version::parse("1.4.0-rc.1").unwrap());
+    ///
+    /// // Bump pre-release version
+    /// let version = version.bump_pre(Increment::Patch, &label);
+    /// assert_eq!(version, Version::parse("1.4.0-rc.2").unwrap());
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    fn bump_pre(&self, increment: Increment, label: &Prerelease) -> Version {
+        let mut version = self.bump(increment);
+        if label.is_empty() {
+            return version;
+        }
+
+        // If the version was promoted, it's a pre-release of the next version,
+        // so we continue counting in case the label matches
+        let mut counter = 1;
+        let promoted = (version.major, version.minor, version.patch)
+            == (self.major, self.minor, self.patch);
+        if !self.pre.is_empty() && promoted {
+            let suffix = self.pre.as_str().strip_prefix(label.as_str());
+            if let Some(n) = suffix.and_then(|rest| rest.strip_prefix('.')) {
+                counter = n.parse::<u64>().map_or(1, |n| n.saturating_add(1));
+            }
+        }
+
+        // Append label and counter, which is always a valid pre-release, as
+        // the label is valid and the counter is numeric
+        let pre = format!("{label}.{counter}");
+        version.pre = Prerelease::new(&pre).expect("invariant");
+
+        // If the label orders before the current one, the version would move
+        // backwards, so we apply the increment again to the promoted version
+        if version <= *self {
+            let mut next = self.bump(increment).bump(increment);
+            next.pre =
+                Prerelease::new(&format!("{label}.1")).expect("invariant");
+            return next;
+        }
+
+        // Return next version
         version
     }
 
@@ -138,3 +230,91 @@ impl VersionExt for Version {
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod bump_pre {
+        use semver::{Prerelease, Version};
+
+        use crate::project::version::{Increment, VersionExt};
+
+        /// Bumps the given version to a pre-release with the given label.
+        fn bump(version: &str, increment: Increment, label: &str) -> String {
+            let version = Version::parse(version).expect("valid version");
+            let label = Prerelease::new(label).expect("valid label");
+            version.bump_pre(increment, &label).to_string()
+        }
+
+        #[test]
+        fn handles_stable_version() {
+            assert_eq!(bump("1.3.2", Increment::Patch, "rc"), "1.3.3-rc.1");
+            assert_eq!(bump("1.3.2", Increment::Minor, "rc"), "1.4.0-rc.1");
+            assert_eq!(bump("1.3.2", Increment::Major, "rc"), "2.0.0-rc.1");
+        }
+
+        #[test]
+        fn handles_counter_increment() {
+            assert_eq!(
+                bump("1.4.0-rc.1", Increment::Patch, "rc"),
+                "1.4.0-rc.2"
+            );
+            assert_eq!(
+                bump("1.4.0-rc.9", Increment::Minor, "rc"),
+                "1.4.0-rc.10"
+            );
+        }
+
+        #[test]
+        fn handles_promotion_with_higher_increment() {
+            assert_eq!(
+                bump("1.4.0-rc.2", Increment::Major, "rc"),
+                "2.0.0-rc.1"
+            );
+            assert_eq!(
+                bump("0.4.1-rc.2", Increment::Minor, "rc"),
+                "0.5.0-rc.1"
+            );
+        }
+
+        #[test]
+        fn handles_label_switch_to_higher_label() {
+            assert_eq!(
+                bump("1.4.0-beta.3", Increment::Patch, "rc"),
+                "1.4.0-rc.1"
+            );
+            assert_eq!(
+                bump("1.4.0-alpha.1", Increment::Minor, "beta"),
+                "1.4.0-beta.1"
+            );
+        }
+
+        #[test]
+        fn handles_label_switch_to_lower_label() {
+            assert_eq!(
+                bump("1.0.0-rc.1", Increment::Patch, "beta"),
+                "1.0.1-beta.1"
+            );
+            assert_eq!(
+                bump("1.0.0-rc.1", Increment::Minor, "beta"),
+                "1.1.0-beta.1"
+            );
+            assert_eq!(
+                bump("0.0.3-rc.1", Increment::Major, "alpha"),
+                "0.0.4-alpha.1"
+            );
+        }
+
+        #[test]
+        fn handles_empty_label() {
+            let version = Version::parse("1.4.0-rc.1").expect("valid version");
+            let version =
+                version.bump_pre(Increment::Patch, &Prerelease::EMPTY);
+            assert_eq!(version, Version::new(1, 4, 0));
+        }
+    }
+}
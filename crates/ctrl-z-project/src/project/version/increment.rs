@@ -25,14 +25,19 @@
 
 //! Version increment.
 
+use serde::Serialize;
 use std::fmt;
+use std::str::FromStr;
+
+use crate::project::{Error, Result};
 
 // ----------------------------------------------------------------------------
 // Enums
 // ----------------------------------------------------------------------------
 
 /// Version increment.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Increment {
     /// Patch increment.
     Patch,
@@ -46,6 +51,41 @@ pub enum Increment {
 // Trait implementations
 // ----------------------------------------------------------------------------
 
+impl FromStr for Increment {
+    type Err = Error;
+
+    /// Attempts to create a version increment from a string.
+    ///
+    /// # Errors
+    ///
+    /// This methods return [`Error::Increment`], if the string does not match
+    /// a valid [`Increment`] variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ctrl_z_project::version::Increment;
+    ///
+    /// // Create version increment from string
+    /// let increment: Increment = "minor".parse()?;
+    /// assert_eq!(increment, Increment::Minor);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "patch" => Ok(Increment::Patch),
+            "minor" => Ok(Increment::Minor),
+            "major" => Ok(Increment::Major),
+            _ => Err(Error::Increment),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 impl fmt::Display for Increment {
     /// Formats the increment for display.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -35,8 +35,7 @@ use super::Project;
 
 pub mod dependents;
 mod packages;
-pub mod updater;
-pub mod writer; // @todo
+pub mod writer;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -119,6 +118,13 @@ where
         Self::read(T::resolve(path.as_ref())?)
     }
 
+    /// Returns a reference to the workspace path.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     /// Returns a reference to the project with the given name.
     #[inline]
     #[must_use]
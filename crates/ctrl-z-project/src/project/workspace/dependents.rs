@@ -77,11 +77,11 @@ where
         let mut builder = Graph::builder();
 
         // Collect all packages in the workspace, which are all projects that
-        // have a dedicated name and version, and add them as nodes
-        for project in self.projects.values() {
-            if project.manifest.name().is_some() {
-                builder.add_node(project);
-            }
+        // have a dedicated name and version, and add them as nodes - note that
+        // packages are added in the order of their names, so node indices are
+        // identical to the indices of scopes and version increments
+        for path in self.packages.values() {
+            builder.add_node(&self.projects[path]);
         }
 
         // Analyze dependencies between packages by iterating over all projects,
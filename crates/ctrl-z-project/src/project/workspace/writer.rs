@@ -26,35 +26,76 @@
 //! Workspace writer.
 
 use semver::Version;
+use std::collections::btree_map::Iter;
 use std::collections::BTreeMap;
 use std::fs;
 use std::marker::PhantomData;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::project::version::{Increment, VersionExt};
 use crate::project::{Manifest, Project, Result};
 
 use super::Workspace;
 
+mod backup;
 mod cargo;
 mod node;
 
+use backup::persist;
+
+pub use backup::Backup;
+
 // ----------------------------------------------------------------------------
 // Traits
 // ----------------------------------------------------------------------------
 
+/// Writable.
+///
+/// This trait is implemented by [`Writer`] for each supported ecosystem, and
+/// knows how to update the versions of packages and their dependents in the
+/// ecosystem-specific manifest format, as well as how to update the lockfile.
 pub trait Writable {
+    /// Updates the versions in the given manifest content.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the manifest can't be parsed.
     fn write<S>(&self, input: S) -> Result<String>
     where
         S: AsRef<str>;
+
+    /// Returns the path to the lockfile of the workspace at the given path.
+    fn lockfile<P>(&self, path: P) -> PathBuf
+    where
+        P: AsRef<Path>;
+
+    /// Updates the lockfile of the workspace at the given path.
+    ///
+    /// This method must be invoked after all manifests have been written, as
+    /// lockfiles are derived from them, and returns the path to the lockfile,
+    /// or nothing if the workspace doesn't have a lockfile.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the lockfile can't be updated.
+    fn lock<P>(&self, path: P) -> Result<Option<PathBuf>>
+    where
+        P: AsRef<Path>;
 }
 
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
 
-pub struct Writer<'a, T> {
-    items: BTreeMap<&'a str, Version>,
+/// Workspace writer.
+///
+/// Writers are created from a [`Workspace`] and a set of version increments,
+/// and map the names of all packages that are bumped to their next versions.
+#[derive(Debug)]
+pub struct Writer<T> {
+    /// Next versions of packages.
+    versions: BTreeMap<String, Version>,
+    /// Type marker.
     marker: PhantomData<T>,
 }
 
@@ -66,17 +107,137 @@ impl<T> Workspace<T>
 where
     T: Manifest,
 {
-    pub fn apply(&self, increments: &[Option<Increment>]) -> Writer<'_, T> {
-        let mut items = BTreeMap::new();
-        for (node, (_, project)) in self.projects.iter().enumerate() {
-            if let Some(increment) = &increments[node] {
+    /// Creates a writer applying the given version increments.
+    ///
+    /// Version increments must be given in the same order as the packages in
+    /// the workspace, which is the order of [`Workspace::packages`], i.e., the
+    /// packages sorted by name, as well as of the nodes of the graph returned
+    /// by [`Workspace::dependents`].
+    #[must_use]
+    pub fn apply(&self, increments: &[Option<Increment>]) -> Writer<T> {
+        self.apply_with(increments, VersionExt::bump)
+    }
+
+    /// Creates a writer applying the given version increments with a function.
+    ///
+    /// The function computes the next version of a package from its current
+    /// version and increment, e.g., to create pre-release versions with
+    /// [`VersionExt::bump_pre`] or to attach build metadata.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn apply_with<F>(
+        &self, increments: &[Option<Increment>], f: F,
+    ) -> Writer<T>
+    where
+        F: Fn(&Version, Increment) -> Version,
+    {
+        let iter = self.packages.values().map(|path| &self.projects[path]);
+
+        // Compute next versions for all packages with version increments
+        let mut versions = BTreeMap::new();
+        for (project, increment) in iter.zip(increments) {
+            if let Some(increment) = increment {
                 let name = project.name().expect("invariant");
-                let version =
-                    project.version().expect("invariant").bump(*increment);
-                items.insert(name, version);
+                let version = project.version().expect("invariant");
+                versions.insert(name.to_string(), f(version, *increment));
             }
         }
-        Writer { items, marker: PhantomData }
+
+        // Create and return writer
+        Writer { versions, marker: PhantomData }
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<T> Writer<T> {
+    /// Returns the next version of the package with the given name.
+    #[inline]
+    pub fn get<N>(&self, name: N) -> Option<&Version>
+    where
+        N: AsRef<str>,
+    {
+        self.versions.get(name.as_ref())
+    }
+
+    /// Creates an iterator over the names and next versions of packages.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, String, Version> {
+        self.into_iter()
+    }
+
+    /// Returns the number of packages to be bumped.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Returns whether there are any packages to be bumped.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<T> Workspace<T>
+where
+    T: Manifest,
+    Writer<T>: Writable,
+{
+    /// Writes the next versions of the given writer to all manifests, and
+    /// updates the lockfile, returning its path, if any.
+    ///
+    /// Writing is transactional: rewritten manifests are first staged in memory
+    /// and validated by parsing them, so a manifest is never written if it
+    /// can't be read back. Then, all manifests are written atomically, and the
+    /// lockfile is updated. If any of those steps fails, the original contents
+    /// of all manifests and the lockfile are restored, so the workspace is
+    /// never left in a partially bumped state.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][], if a manifest can't be read or
+    /// written, or any error returned by [`Manifest::from_str`][],
+    /// [`Writable::write`] or [`Writable::lock`].
+    ///
+    /// [`Error::Io`]: crate::project::Error::Io
+    /// [`Manifest::from_str`]: std::str::FromStr::from_str
+    pub fn write(&self, writer: &Writer<T>) -> Result<Option<PathBuf>> {
+        let mut staged = Vec::new();
+        for project in self.projects.values() {
+            let content = fs::read_to_string(&project.path)?;
+            let updated = writer.write(&content)?;
+
+            // Only stage manifest if its content changed, and ensure that it
+            // can still be parsed after rewriting it
+            if updated != content {
+                updated.parse::<T>()?;
+                staged.push((&project.path, updated));
+            }
+        }
+
+        // Back up all manifests and the lockfile before touching anything
+        let mut backup = Backup::default();
+        for (path, _) in &staged {
+            backup.add(*path)?;
+        }
+        backup.add(writer.lockfile(&self.path))?;
+
+        // Write all manifests atomically, and update the lockfile afterwards,
+        // since lockfiles are derived from manifests
+        let result = staged
+            .iter()
+            .try_for_each(|(path, content)| persist(path, content.as_bytes()))
+            .and_then(|()| writer.lock(&self.path));
+
+        // Restore backup in case of failure - note that if restoring fails as
+        // well, the workspace is in an inconsistent state, which is more
+        // important to report than the original error
+        result.or_else(|err| {
+            backup.restore()?;
+            Err(err)
+        })
     }
 }
 
@@ -85,15 +246,171 @@ where
 impl<T> Project<T>
 where
     T: Manifest,
-    for<'a> Writer<'a, T>: Writable, // @todo: meg super ugly...
+    Writer<T>: Writable,
 {
-    pub fn write<P>(self, writer: Writer<T>) -> Result
-    where
-        P: AsRef<Path>,
-    {
+    /// Writes the next versions of the given writer to the project manifest.
+    ///
+    /// The manifest is validated by parsing it before it's written atomically,
+    /// but the lockfile is not updated. Use [`Workspace::write`] to update all
+    /// manifests of a workspace together with its lockfile.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][], if the manifest can't be read or
+    /// written, or any error returned by [`Manifest::from_str`][] or
+    /// [`Writable::write`].
+    ///
+    /// [`Error::Io`]: crate::project::Error::Io
+    /// [`Manifest::from_str`]: std::str::FromStr::from_str
+    pub fn write(&self, writer: &Writer<T>) -> Result {
         let content = fs::read_to_string(&self.path)?;
-        let updated = writer.write(content.as_str())?;
-        fs::write(&self.path, updated)?;
+        let updated = writer.write(&content)?;
+
+        // Only write manifest if its content changed
+        if updated != content {
+            updated.parse::<T>()?;
+            persist(&self.path, updated.as_bytes())?;
+        }
+
+        // No errors occurred
         Ok(())
     }
 }
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<'a, T> IntoIterator for &'a Writer<T> {
+    type Item = (&'a String, &'a Version);
+    type IntoIter = Iter<'a, String, Version>;
+
+    /// Creates an iterator over the names and next versions of packages.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.versions.iter()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod apply {
+        use semver::Version;
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::cargo::Cargo;
+        use crate::project::version::Increment;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        /// Creates a workspace whose package paths and names sort differently.
+        fn workspace() -> Result<(TempDir, Workspace<Cargo>)> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            fs::create_dir_all(root.join("packages/core"))?;
+            fs::create_dir_all(root.join("packages/util"))?;
+            fs::write(
+                root.join("Cargo.toml"),
+                "[workspace]\nmembers = [\"packages/core\", \"packages/util\"]\n",
+            )?;
+            fs::write(
+                root.join("packages/core/Cargo.toml"),
+                "[package]\nname = \"zeta\"\nversion = \"1.0.0\"\n\n\
+                 [dependencies]\nalpha = { version = \"1.0.0\" }\n",
+            )?;
+            fs::write(
+                root.join("packages/util/Cargo.toml"),
+                "[package]\nname = \"alpha\"\nversion = \"1.0.0\"\n",
+            )?;
+            let workspace = Workspace::read(root.join("Cargo.toml"))?;
+            Ok((dir, workspace))
+        }
+
+        #[test]
+        fn handles_name_order() -> Result {
+            let (_dir, workspace) = workspace()?;
+            let names = workspace.packages().map(|(_, name)| name);
+            assert_eq!(names.collect::<Vec<_>>(), ["alpha", "zeta"]);
+
+            // Version increments are given in the order of package names
+            let writer = workspace.apply(&[None, Some(Increment::Minor)]);
+            assert_eq!(writer.get("alpha"), None);
+            assert_eq!(writer.get("zeta"), Some(&Version::new(1, 1, 0)));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_name_order_of_dependents() -> Result {
+            let (_dir, workspace) = workspace()?;
+            let dependents = workspace.dependents()?;
+            assert_eq!(dependents[0].name(), Some("alpha"));
+            assert_eq!(dependents[1].name(), Some("zeta"));
+            Ok(())
+        }
+    }
+
+    mod write {
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::cargo::Cargo;
+        use crate::project::version::Increment;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        /// Manifest of the workspace root.
+        const ROOT: &str = "[workspace]\nmembers = [\"core\"]\n";
+
+        /// Manifest of the workspace member.
+        const CORE: &str = "[package]\nname = \"core\"\n\
+                            version = \"1.0.0\"\n";
+
+        #[test]
+        fn handles_manifests() -> Result {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            fs::create_dir_all(root.join("core"))?;
+            fs::write(root.join("Cargo.toml"), ROOT)?;
+            fs::write(root.join("core/Cargo.toml"), CORE)?;
+
+            // Write next versions, without a lockfile being present
+            let workspace = Workspace::<Cargo>::read(root.join("Cargo.toml"))?;
+            let writer = workspace.apply(&[Some(Increment::Minor)]);
+            assert_eq!(workspace.write(&writer)?, None);
+            assert_eq!(
+                fs::read_to_string(root.join("core/Cargo.toml"))?,
+                CORE.replace("1.0.0", "1.1.0")
+            );
+            assert!(!root.join("Cargo.lock").exists());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_rollback_on_lock_failure() -> Result {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            fs::create_dir_all(root.join("core"))?;
+            fs::write(root.join("Cargo.toml"), ROOT)?;
+            fs::write(root.join("core/Cargo.toml"), CORE)?;
+            fs::write(root.join("Cargo.lock"), "[[package]\n")?;
+
+            // Updating the invalid lockfile fails after the manifests were
+            // persisted, which must restore the original manifests
+            let workspace = Workspace::<Cargo>::read(root.join("Cargo.toml"))?;
+            let writer = workspace.apply(&[Some(Increment::Minor)]);
+            assert!(workspace.write(&writer).is_err());
+            assert_eq!(fs::read_to_string(root.join("core/Cargo.toml"))?, CORE);
+            assert_eq!(
+                fs::read_to_string(root.join("Cargo.lock"))?,
+                "[[package]\n"
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Workspace writer backup.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+
+use crate::project::Result;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Workspace writer backup.
+///
+/// Backups hold the original contents of all files touched by a writer, so
+/// they can be restored if writing any of them fails. Files that didn't exist
+/// before are removed on restore, which is relevant for lockfiles.
+#[derive(Debug, Default)]
+pub struct Backup {
+    /// Paths and original contents of files.
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Backup {
+    /// Backs up the original content of the file at the given path.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][] if the file exists, but can't be
+    /// read.
+    ///
+    /// [`Error::Io`]: crate::project::Error::Io
+    pub fn add<P>(&mut self, path: P) -> Result
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let content = match fs::read(&path) {
+            Ok(content) => Some(content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        // Add file to backup
+        self.files.push((path, content));
+        Ok(())
+    }
+
+    /// Restores the original contents of all files in the backup.
+    ///
+    /// All files are restored, even if restoring one of them fails, so as many
+    /// files as possible are brought back to their original state.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][] if a file can't be restored, which
+    /// is the first error that occurred.
+    ///
+    /// [`Error::Io`]: crate::project::Error::Io
+    pub fn restore(&self) -> Result {
+        let mut result = Ok(());
+        for (path, content) in &self.files {
+            let res = match content {
+                Some(content) => persist(path, content),
+                None => match fs::remove_file(path) {
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                        Err(err.into())
+                    }
+                    _ => Ok(()),
+                },
+            };
+
+            // Keep the first error, but continue restoring other files
+            if result.is_ok() {
+                result = res;
+            }
+        }
+
+        // Return first error, if any
+        result
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Writes the given content to the file at the given path atomically.
+///
+/// The content is written to a temporary file in the same directory, which is
+/// then renamed to the given path, so the file is either fully written or not
+/// touched at all. Permissions of an existing file are carried over, since
+/// temporary files are only readable by their owner.
+///
+/// # Errors
+///
+/// This function returns [`Error::Io`][] if the operation fails.
+///
+/// [`Error::Io`]: crate::project::Error::Io
+pub fn persist<P>(path: P, content: &[u8]) -> Result
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty());
+    let dir = dir.unwrap_or_else(|| Path::new("."));
+    let mut temp = NamedTempFile::new_in(dir)?;
+    temp.write_all(content)?;
+    temp.as_file().sync_all()?;
+
+    // Carry over permissions of the existing file, if any
+    if let Ok(metadata) = fs::metadata(path) {
+        temp.as_file().set_permissions(metadata.permissions())?;
+    }
+
+    // Replace file with temporary file
+    temp.persist(path).map_err(|err| err.error)?;
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod restore {
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::workspace::writer::backup::{persist, Backup};
+        use crate::project::Result;
+
+        #[test]
+        fn handles_existing_file() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("Cargo.toml");
+            fs::write(&path, "original")?;
+
+            // Back up file, overwrite and restore it
+            let mut backup = Backup::default();
+            backup.add(&path)?;
+            persist(&path, b"updated")?;
+            backup.restore()?;
+            assert_eq!(fs::read_to_string(&path)?, "original");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_new_file() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("Cargo.lock");
+
+            // Back up missing file, create and restore it
+            let mut backup = Backup::default();
+            backup.add(&path)?;
+            persist(&path, b"created")?;
+            backup.restore()?;
+            assert!(!path.exists());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_new_file_already_removed() -> Result {
+            let dir = TempDir::new()?;
+            let mut backup = Backup::default();
+            backup.add(dir.path().join("Cargo.lock"))?;
+            backup.restore()
+        }
+
+        #[test]
+        fn errors_after_restoring_all_files() -> Result {
+            let dir = TempDir::new()?;
+            let first = dir.path().join("a/Cargo.toml");
+            let second = dir.path().join("b/Cargo.toml");
+            for path in [&first, &second] {
+                fs::create_dir_all(path.parent().expect("invariant"))?;
+                fs::write(path, "original")?;
+            }
+
+            // Back up both files, then remove the first file's directory, so
+            // restoring it fails, and overwrite the second file
+            let mut backup = Backup::default();
+            backup.add(&first)?;
+            backup.add(&second)?;
+            fs::remove_dir_all(dir.path().join("a"))?;
+            persist(&second, b"updated")?;
+            assert!(backup.restore().is_err());
+            assert_eq!(fs::read_to_string(&second)?, "original");
+            Ok(())
+        }
+    }
+}
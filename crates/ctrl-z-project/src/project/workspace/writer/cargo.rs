@@ -23,44 +23,80 @@
 
 // ----------------------------------------------------------------------------
 
-//! @todo tbd
+//! Cargo workspace writer.
 
 use semver::Version;
-use toml_edit::{value, DocumentMut, Item, TableLike};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use toml_edit::{value, DocumentMut, Item, TableLike, Value};
 
 use crate::project::manifest::cargo::Cargo;
 use crate::project::Result;
 
+use super::backup::persist;
 use super::{Writable, Writer};
 
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl Writable for Writer<'_, Cargo> {
+impl Writable for Writer<Cargo> {
+    /// Updates the versions in the given manifest content.
+    ///
+    /// Since we're using [`toml_edit`], formatting and comments are preserved,
+    /// so the resulting diff only contains the changed versions.
     fn write<S>(&self, input: S) -> Result<String>
     where
         S: AsRef<str>,
     {
-        let content = input.as_ref();
-        // @todo: move writing in and out? that way we can safe the path! no need!
-        // we might just parse this from string? again?
-        let mut doc = content.parse::<DocumentMut>()?;
+        let mut doc = input.as_ref().parse::<DocumentMut>()?;
         update_workspace_dependencies(&mut doc, self);
         update_package_version(&mut doc, self);
         update_dependencies(&mut doc, self);
 
-        // we need to wait for the cargo toml to catch up.
+        // Return updated manifest
+        Ok(doc.to_string())
+    }
+
+    /// Returns the path to the lockfile of the workspace at the given path.
+    fn lockfile<P>(&self, path: P) -> PathBuf
+    where
+        P: AsRef<Path>,
+    {
+        path.as_ref().join("Cargo.lock")
+    }
 
-        // enforce waiting for Cargo.lock
-        let output = std::process::Command::new("cargo")
-            .arg("update")
-            .arg("--workspace")
-            .arg("--offline")
-            .output()
-            .unwrap();
+    /// Updates the lockfile of the workspace at the given path.
+    ///
+    /// Rather than invoking `cargo update`, which requires `cargo` and might
+    /// require network access, we only rewrite the versions of workspace
+    /// members in the lockfile, since they're the only packages that changed,
+    /// which keeps the resulting diff minimal.
+    fn lock<P>(&self, path: P) -> Result<Option<PathBuf>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.lockfile(path);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(None);
+            }
+            Err(err) => return Err(err.into()),
+        };
 
-        Ok(doc.to_string())
+        // Update lockfile, and only write it if its content changed
+        let mut doc = content.parse::<DocumentMut>()?;
+        update_lockfile(&mut doc, self);
+        let updated = doc.to_string();
+        if updated != content {
+            persist(&path, updated.as_bytes())?;
+        }
+
+        // Return path to lockfile
+        Ok(Some(path))
     }
 }
 
@@ -68,6 +104,66 @@ impl Writable for Writer<'_, Cargo> {
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Updates `[[package]]` entries of workspace members with new versions.
+///
+/// Workspace members are the only packages without a `source`. Dependencies
+/// are usually referenced by name, but if multiple versions of a package are
+/// locked, they're referenced by name and version, e.g., `foo 1.0.0`, which
+/// is why those references are updated as well.
+fn update_lockfile(doc: &mut DocumentMut, writer: &Writer<Cargo>) {
+    let Some(packages) = doc
+        .get_mut("package")
+        .and_then(|item| item.as_array_of_tables_mut())
+    else {
+        return;
+    };
+
+    // Update versions of workspace members, and remember their references
+    let mut references = BTreeMap::new();
+    for package in packages.iter_mut() {
+        if package.contains_key("source") {
+            continue;
+        }
+
+        // Obtain name and current version of workspace member
+        let Some(name) = package.get("name").and_then(Item::as_str) else {
+            continue;
+        };
+        let Some(version) = writer.get(name) else {
+            continue;
+        };
+        let name = name.to_string();
+        if let Some(value) =
+            package.get_mut("version").and_then(Item::as_value_mut)
+        {
+            if let Some(current) = value.as_str() {
+                references.insert(
+                    format!("{name} {current}"),
+                    format!("{name} {version}"),
+                );
+            }
+            replace(value, version.to_string());
+        }
+    }
+
+    // Update references to workspace members in dependencies
+    for package in packages.iter_mut() {
+        if let Some(array) = package
+            .get_mut("dependencies")
+            .and_then(|item| item.as_array_mut())
+        {
+            for value in array.iter_mut() {
+                let reference = value
+                    .as_str()
+                    .and_then(|reference| references.get(reference));
+                if let Some(reference) = reference {
+                    replace(value, reference.clone());
+                }
+            }
+        }
+    }
+}
+
 /// Updates `[workspace.dependencies]` with new versions.
 fn update_workspace_dependencies(
     doc: &mut DocumentMut, writer: &Writer<Cargo>,
@@ -88,7 +184,7 @@ fn update_package_version(doc: &mut DocumentMut, writer: &Writer<Cargo>) {
         .and_then(|item| item.as_table_like_mut())
     {
         if let Some(name) = package.get("name").and_then(|item| item.as_str()) {
-            if let Some(version) = writer.items.get(name) {
+            if let Some(version) = writer.get(name) {
                 package.insert("version", value(version.to_string()));
             }
         }
@@ -112,12 +208,19 @@ fn update_dependencies(doc: &mut DocumentMut, writer: &Writer<Cargo>) {
 /// Updates a dependency table with new versions.
 fn update_dependency_table(table: &mut dyn TableLike, writer: &Writer<Cargo>) {
     for (name, item) in table.iter_mut() {
-        if let Some(version) = writer.items.get(name.get()) {
+        if let Some(version) = writer.get(name.get()) {
             update_dependency(item, version);
         }
     }
 }
 
+/// Replaces a string value, preserving its surrounding whitespace.
+fn replace(value: &mut Value, content: String) {
+    let decor = value.decor().clone();
+    *value = Value::from(content);
+    *value.decor_mut() = decor;
+}
+
 /// Updates a dependency with a new version.
 fn update_dependency(item: &mut Item, version: &Version) {
     if let Some(table) = item.as_table_like() {
@@ -138,3 +241,108 @@ fn update_dependency(item: &mut Item, version: &Version) {
         table.insert("version", value(version.to_string()));
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod update_lockfile {
+        use semver::Version;
+        use std::collections::BTreeMap;
+        use std::marker::PhantomData;
+        use toml_edit::DocumentMut;
+
+        use crate::project::manifest::cargo::Cargo;
+        use crate::project::workspace::writer::cargo::update_lockfile;
+        use crate::project::workspace::writer::Writer;
+        use crate::project::Result;
+
+        /// Updates the given lockfile, bumping `foo` to `1.1.0`.
+        fn update(input: &str) -> Result<String> {
+            let writer = Writer::<Cargo> {
+                versions: BTreeMap::from([(
+                    String::from("foo"),
+                    Version::new(1, 1, 0),
+                )]),
+                marker: PhantomData,
+            };
+            let mut doc = input.parse::<DocumentMut>()?;
+            update_lockfile(&mut doc, &writer);
+            Ok(doc.to_string())
+        }
+
+        #[test]
+        fn handles_lockfiles() -> Result {
+            for (input, expected) in [
+                // Workspace member is rewritten
+                (
+                    "[[package]]\nname = \"foo\"\nversion = \"1.0.0\"\n",
+                    "[[package]]\nname = \"foo\"\nversion = \"1.1.0\"\n",
+                ),
+                // Workspace member that isn't bumped is untouched
+                (
+                    "[[package]]\nname = \"bar\"\nversion = \"1.0.0\"\n",
+                    "[[package]]\nname = \"bar\"\nversion = \"1.0.0\"\n",
+                ),
+                // Registry package with the same name is untouched
+                (
+                    "[[package]]\nname = \"foo\"\nversion = \"1.0.0\"\n\
+                     source = \"registry+https://github.com/\
+                     rust-lang/crates.io-index\"\n",
+                    "[[package]]\nname = \"foo\"\nversion = \"1.0.0\"\n\
+                     source = \"registry+https://github.com/\
+                     rust-lang/crates.io-index\"\n",
+                ),
+                // Name and version references to members are rewritten
+                (
+                    "[[package]]\nname = \"foo\"\nversion = \"1.0.0\"\n\n\
+                     [[package]]\nname = \"bar\"\nversion = \"1.0.0\"\n\
+                     dependencies = [\n \"foo 1.0.0\",\n \"foo 0.9.0\",\n]\n",
+                    "[[package]]\nname = \"foo\"\nversion = \"1.1.0\"\n\n\
+                     [[package]]\nname = \"bar\"\nversion = \"1.0.0\"\n\
+                     dependencies = [\n \"foo 1.1.0\",\n \"foo 0.9.0\",\n]\n",
+                ),
+                // Name references and references to non-members are untouched
+                (
+                    "[[package]]\nname = \"foo\"\nversion = \"1.0.0\"\n\n\
+                     [[package]]\nname = \"bar\"\nversion = \"1.0.0\"\n\
+                     dependencies = [\n \"foo\",\n \"baz 1.0.0\",\n]\n",
+                    "[[package]]\nname = \"foo\"\nversion = \"1.1.0\"\n\n\
+                     [[package]]\nname = \"bar\"\nversion = \"1.0.0\"\n\
+                     dependencies = [\n \"foo\",\n \"baz 1.0.0\",\n]\n",
+                ),
+                // Lockfile without packages is untouched
+                ("version = 4\n", "version = 4\n"),
+            ] {
+                assert_eq!(update(input)?, expected);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_minimal_diff() -> Result {
+            let input = "# This file is automatically @generated by Cargo.\n\
+                         # It is not intended for manual editing.\n\
+                         version = 4\n\n\
+                         [[package]]\n\
+                         name = \"bar\"\n\
+                         version = \"0.1.0\"\n\
+                         source = \"registry+https://github.com/\
+                         rust-lang/crates.io-index\"\n\
+                         checksum = \"0123456789abcdef\"\n\n\
+                         [[package]]\n\
+                         name = \"foo\"\n\
+                         version =   \"1.0.0\"  # keep\n\
+                         dependencies = [\n \"bar\",\n]\n";
+
+            // Only the version of the workspace member must differ
+            let output = update(input)?;
+            let expected = input.replace("\"1.0.0\"", "\"1.1.0\"");
+            assert_eq!(output, expected);
+            Ok(())
+        }
+    }
+}
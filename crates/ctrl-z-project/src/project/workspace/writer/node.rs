@@ -23,64 +23,96 @@
 
 // ----------------------------------------------------------------------------
 
-//! @todo tbd
+//! Node workspace writer.
 
-use serde_json::{Map, Value};
+use semver::Version;
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use serde_json::{Map, Serializer, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 
 use crate::project::manifest::node::Node;
 use crate::project::Result;
 
+use super::backup::persist;
 use super::{Writable, Writer};
 
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
 
-impl Writable for Writer<'_, Node> {
+impl Writable for Writer<Node> {
+    /// Updates the versions in the given manifest content.
     fn write<S>(&self, input: S) -> Result<String>
     where
         S: AsRef<str>,
     {
-        let content = input.as_ref();
-        let mut doc = serde_json::from_str::<Value>(content)?;
-        // update_workspace_dependencies(&mut doc, versions);
-        // NPM_update_package_version(&mut doc, versions);
-
-        // Update package version if name matches
-        if let Some(obj) = doc.as_object_mut() {
-            if let Some(name) = obj.get("name").and_then(|n| n.as_str()) {
-                if let Some(new_version) = self.items.get(name) {
-                    obj.insert(
-                        "version".to_string(),
-                        Value::String(new_version.to_string()),
-                    );
-                }
-            }
+        let input = input.as_ref();
+        let mut doc = serde_json::from_str::<Value>(input)?;
+        let original = doc.clone();
+        if let Some(object) = doc.as_object_mut() {
+            update_package_version(object, self);
+            update_dependencies(object, self);
+        }
 
-            // Update dependencies sections - only normal deps for now
-            if let Some(deps) = obj
-                .get_mut("dependencies")
-                .and_then(|value| value.as_object_mut())
-            {
-                update_npm_dependency_table(deps, self);
-            }
+        // Return manifest unchanged if no value was updated, since serializing
+        // it might change its formatting, e.g., of inline arrays
+        if doc == original {
+            return Ok(input.to_string());
         }
 
-        let mut content = serde_json::to_string_pretty(&doc)?;
-        content.push('\n');
+        // Return updated manifest, keeping the original indentation
+        to_string(&doc, indentation(input))
+    }
 
-        // Try npm first
-        let npm_result = std::process::Command::new("npm")
-            .args(["install", "--package-lock-only", "--ignore-scripts"])
-            .output();
+    /// Returns the path to the lockfile of the workspace at the given path.
+    fn lockfile<P>(&self, path: P) -> PathBuf
+    where
+        P: AsRef<Path>,
+    {
+        path.as_ref().join("package-lock.json")
+    }
 
-        if let Ok(output) = npm_result {
-            if output.status.success() {
-                // @todo?
+    /// Updates the lockfile of the workspace at the given path.
+    ///
+    /// Rather than invoking `npm install`, which requires `npm` and might run
+    /// into network access or resolve different versions of dependencies, we
+    /// only rewrite the entries of workspace members in the lockfile, keeping
+    /// the order of keys, so releases are hermetic and deterministic.
+    fn lock<P>(&self, path: P) -> Result<Option<PathBuf>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.lockfile(path);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(None);
             }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Update lockfile, including the legacy format used before version 3
+        let mut doc = serde_json::from_str::<Value>(&content)?;
+        let original = doc.clone();
+        if let Some(object) = doc.as_object_mut() {
+            update_package_version(object, self);
+            update_lockfile_packages(object, self);
+            update_lockfile_dependencies(object, self);
+        }
+
+        // Only write lockfile if a value was updated, keeping the original
+        // indentation, as for manifests
+        if doc != original {
+            let updated = to_string(&doc, indentation(&content))?;
+            persist(&path, updated.as_bytes())?;
         }
 
-        Ok(content)
+        // Return path to lockfile
+        Ok(Some(path))
     }
 }
 
@@ -88,12 +120,402 @@ impl Writable for Writer<'_, Node> {
 // Functions
 // ----------------------------------------------------------------------------
 
-fn update_npm_dependency_table(
-    map: &mut Map<String, Value>, writer: &Writer<Node>,
+/// Updates `version` with new versions.
+fn update_package_version(
+    object: &mut Map<String, Value>, writer: &Writer<Node>,
 ) {
+    let name = object.get("name").and_then(Value::as_str);
+    if let Some(version) = name.and_then(|name| writer.get(name)) {
+        let version = Value::String(version.to_string());
+        object.insert("version".to_string(), version);
+    }
+}
+
+/// Updates `packages` entries of workspace members with new versions.
+///
+/// Workspace members are linked from `node_modules`, e.g., `node_modules/foo`
+/// resolves to `packages/foo`, which is the entry holding the version and the
+/// dependencies of the member, and the root package has an empty key.
+fn update_lockfile_packages(
+    object: &mut Map<String, Value>, writer: &Writer<Node>,
+) {
+    let Some(packages) =
+        object.get_mut("packages").and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+
+    // Resolve the paths of workspace members to their names from links
+    let mut members = BTreeMap::new();
+    for (key, value) in packages.iter() {
+        let Some(name) = key.strip_prefix("node_modules/") else {
+            continue;
+        };
+        if value.get("link").and_then(Value::as_bool) == Some(true) {
+            if let Some(path) = value.get("resolved").and_then(Value::as_str) {
+                members.insert(path.to_string(), name.to_string());
+            }
+        }
+    }
+
+    // Update versions and dependencies of the root package and all members
+    for (key, value) in packages.iter_mut() {
+        if !(key.is_empty() || members.contains_key(key)) {
+            continue;
+        }
+        let Some(entry) = value.as_object_mut() else {
+            continue;
+        };
+
+        // Entries of members might omit the name, so fall back to the link
+        let name = entry.get("name").and_then(Value::as_str);
+        let name = name.or_else(|| members.get(key).map(String::as_str));
+        if let Some(version) = name.and_then(|name| writer.get(name)) {
+            let version = Value::String(version.to_string());
+            entry.insert("version".to_string(), version);
+        }
+        update_dependencies(entry, writer);
+    }
+}
+
+/// Updates legacy `dependencies` entries of workspace members.
+///
+/// Lockfiles before version 3 additionally list all packages by name, where
+/// workspace members are usually referenced by path, e.g., `file:packages/foo`,
+/// so only plain versions are updated, as well as the version ranges of the
+/// dependencies in `requires`. Entries of registry packages are skipped.
+fn update_lockfile_dependencies(
+    object: &mut Map<String, Value>, writer: &Writer<Node>,
+) {
+    let Some(dependencies) = object
+        .get_mut("dependencies")
+        .and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+
+    // Update versions and dependencies of all members
+    for (name, value) in dependencies.iter_mut() {
+        let Some(version) = writer.get(name) else {
+            continue;
+        };
+        let Some(entry) = value.as_object_mut() else {
+            continue;
+        };
+        if entry.contains_key("resolved") {
+            continue;
+        }
+
+        // Only update plain versions, since links must be kept as they are
+        let current = entry.get("version").and_then(Value::as_str);
+        if current.is_some_and(|current| current.parse::<Version>().is_ok()) {
+            let version = Value::String(version.to_string());
+            entry.insert("version".to_string(), version);
+        }
+        if let Some(map) =
+            entry.get_mut("requires").and_then(Value::as_object_mut)
+        {
+            update_dependency_map(map, writer);
+        }
+    }
+}
+
+/// Updates `dependencies` and `devDependencies` with new versions.
+fn update_dependencies(object: &mut Map<String, Value>, writer: &Writer<Node>) {
+    for section in ["dependencies", "devDependencies"] {
+        if let Some(map) = object
+            .get_mut(section)
+            .and_then(|value| value.as_object_mut())
+        {
+            update_dependency_map(map, writer);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Serializes the given document with the given indentation.
+///
+/// The document is terminated with a trailing line feed, which is what npm
+/// does as well, so the diff only contains the changed versions.
+fn to_string(doc: &Value, indent: &str) -> Result<String> {
+    let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = Serializer::with_formatter(Vec::new(), formatter);
+    doc.serialize(&mut serializer)?;
+
+    // Serialized JSON is always valid UTF-8
+    let mut content =
+        String::from_utf8_lossy(&serializer.into_inner()).into_owned();
+    content.push('\n');
+    Ok(content)
+}
+
+/// Detects the indentation of the given document.
+///
+/// The indentation is taken from the first indented line, which is usually
+/// the first key of the top-level object, and defaults to two spaces, which
+/// is what npm uses, e.g., for documents without nested values.
+fn indentation(content: &str) -> &str {
+    content
+        .lines()
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+}
+
+// ----------------------------------------------------------------------------
+
+/// Updates a dependency map with new versions.
+///
+/// Only version ranges consisting of a single version, optionally preceded by
+/// an operator, are updated, keeping the operator, e.g., `~1.0.0` becomes
+/// `~1.1.0`. Other specifications like `*`, `workspace:*`, `file:` or `link:`
+/// are kept as they are, since they don't pin a version.
+fn update_dependency_map(map: &mut Map<String, Value>, writer: &Writer<Node>) {
     for (name, value) in map.iter_mut() {
-        if let Some(version) = writer.items.get(name.as_str()) {
-            *value = Value::String(format!("^{version}"));
+        let Some(version) = writer.get(name) else {
+            continue;
+        };
+        if let Some(spec) = value.as_str().and_then(|spec| range(spec, version))
+        {
+            *value = Value::String(spec);
+        }
+    }
+}
+
+/// Returns the given version range with its version replaced, if applicable.
+///
+/// The `workspace:` protocol is kept, e.g., `workspace:^1.0.0`, as it's only
+/// replaced by package managers when publishing.
+fn range(spec: &str, version: &Version) -> Option<String> {
+    let (protocol, spec) = match spec.strip_prefix("workspace:") {
+        Some(spec) => ("workspace:", spec),
+        None => ("", spec),
+    };
+
+    // Split operator from version, and ensure the remainder is a version
+    let index = spec.find(|char: char| char.is_ascii_digit())?;
+    let (operator, current) = spec.split_at(index);
+    if !["", "^", "~", "=", ">="].contains(&operator) {
+        return None;
+    }
+    current.parse::<Version>().ok()?;
+
+    // Return version range with replaced version
+    Some(format!("{protocol}{operator}{version}"))
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod lock {
+        use semver::Version;
+        use std::collections::BTreeMap;
+        use std::fs;
+        use std::marker::PhantomData;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::node::Node;
+        use crate::project::workspace::writer::{Writable, Writer};
+        use crate::project::Result;
+
+        /// Updates the given lockfile, bumping `foo` to `1.1.0`.
+        fn update(input: &str) -> Result<String> {
+            let writer = Writer::<Node> {
+                versions: BTreeMap::from([(
+                    String::from("foo"),
+                    Version::new(1, 1, 0),
+                )]),
+                marker: PhantomData,
+            };
+            let dir = TempDir::new()?;
+            fs::write(dir.path().join("package-lock.json"), input)?;
+            let path = writer.lock(dir.path())?.expect("invariant");
+            Ok(fs::read_to_string(path)?)
+        }
+
+        #[test]
+        fn handles_v3() -> Result {
+            let input = r#"{
+  "name": "root",
+  "version": "0.0.0",
+  "lockfileVersion": 3,
+  "requires": true,
+  "packages": {
+    "": {
+      "name": "root",
+      "version": "0.0.0",
+      "workspaces": [
+        "packages/*"
+      ]
+    },
+    "node_modules/foo": {
+      "resolved": "packages/foo",
+      "link": true
+    },
+    "node_modules/bar": {
+      "resolved": "packages/bar",
+      "link": true
+    },
+    "node_modules/zod": {
+      "version": "1.0.0",
+      "resolved": "https://registry.npmjs.org/zod/-/zod-1.0.0.tgz"
+    },
+    "packages/foo": {
+      "version": "1.0.0"
+    },
+    "packages/bar": {
+      "version": "1.0.0",
+      "dependencies": {
+        "zod": "^1.0.0",
+        "foo": "^1.0.0"
+      }
+    },
+    "packages/bar/node_modules/foo": {
+      "version": "1.0.0",
+      "resolved": "https://registry.npmjs.org/foo/-/foo-1.0.0.tgz"
+    }
+  }
+}
+"#;
+
+            // Only the member and the dependency on it must be updated
+            let expected = input
+                .replacen(
+                    "\"packages/foo\": {\n      \"version\": \"1.0.0\"",
+                    "\"packages/foo\": {\n      \"version\": \"1.1.0\"",
+                    1,
+                )
+                .replacen("\"foo\": \"^1.0.0\"", "\"foo\": \"^1.1.0\"", 1);
+            assert_eq!(update(input)?, expected);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_v1() -> Result {
+            let input = r#"{
+  "name": "root",
+  "version": "0.0.0",
+  "lockfileVersion": 1,
+  "requires": true,
+  "dependencies": {
+    "foo": {
+      "version": "file:packages/foo"
+    },
+    "bar": {
+      "version": "1.0.0",
+      "requires": {
+        "foo": "^1.0.0"
+      }
+    },
+    "zod": {
+      "version": "1.0.0",
+      "resolved": "https://registry.npmjs.org/zod/-/zod-1.0.0.tgz",
+      "requires": {
+        "foo": "^1.0.0"
+      }
+    }
+  }
+}
+"#;
+
+            // Links and registry packages must be kept as they are
+            assert_eq!(update(input)?, input);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_v1_plain_version() -> Result {
+            let input = r#"{
+  "lockfileVersion": 1,
+  "dependencies": {
+    "foo": {
+      "version": "1.0.0",
+      "requires": {
+        "foo": "^1.0.0"
+      }
+    }
+  }
+}
+"#;
+            assert_eq!(update(input)?, input.replace("1.0.0", "1.1.0"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_indentation() -> Result {
+            for indent in ["    ", "\t"] {
+                let input = format!(
+                    "{{\n{indent}\"name\": \"foo\",\n\
+                     {indent}\"version\": \"1.0.0\"\n}}\n"
+                );
+                assert_eq!(update(&input)?, input.replace("1.0.0", "1.1.0"));
+            }
+            Ok(())
+        }
+    }
+
+    mod write {
+        use semver::Version;
+        use std::collections::BTreeMap;
+        use std::marker::PhantomData;
+
+        use crate::project::manifest::node::Node;
+        use crate::project::workspace::writer::{Writable, Writer};
+        use crate::project::Result;
+
+        /// Updates the given manifest, bumping `foo` to `1.1.0`.
+        fn update(input: &str) -> Result<String> {
+            let writer = Writer::<Node> {
+                versions: BTreeMap::from([(
+                    String::from("foo"),
+                    Version::new(1, 1, 0),
+                )]),
+                marker: PhantomData,
+            };
+            writer.write(input)
+        }
+
+        #[test]
+        fn handles_untouched_manifest() -> Result {
+            let input = "{\n  \"name\": \"bar\",\n  \"files\": [\"dist\"]\n}";
+            assert_eq!(update(input)?, input);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_range_operators() -> Result {
+            for spec in ["^", "~", "=", ">=", "", "workspace:^"] {
+                let input = format!(
+                    "{{\n  \"dependencies\": {{\n    \
+                     \"foo\": \"{spec}1.0.0\"\n  }}\n}}\n"
+                );
+                assert_eq!(update(&input)?, input.replace("1.0.0", "1.1.0"));
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_non_range_specs() -> Result {
+            for spec in [
+                "*",
+                "workspace:*",
+                "file:../foo",
+                "link:../foo",
+                ">=1.0.0 <2.0.0",
+                "<1.0.0",
+            ] {
+                let input = format!(
+                    "{{\n  \"dependencies\": {{\n    \
+                     \"foo\": \"{spec}\"\n  }}\n}}\n"
+                );
+                assert_eq!(update(&input)?, input);
+            }
+            Ok(())
         }
     }
 }
@@ -30,6 +30,8 @@
 mod repository;
 
 pub use repository::commit::{self, Commit};
+pub use repository::date::{self, Date};
+pub use repository::forge::{self, Forge};
 pub use repository::id::{self, Id};
 pub use repository::versions::{self, Versions};
 pub use repository::{Error, Repository, Result};
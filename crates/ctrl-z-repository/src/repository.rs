@@ -26,16 +26,21 @@
 //! Repository.
 
 use std::fmt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 pub mod commit;
+pub mod date;
 mod error;
+pub mod forge;
 pub mod id;
 pub mod versions;
 
 pub use error::{Error, Result};
 
+use forge::Forge;
+use id::Id;
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -44,6 +49,12 @@ pub use error::{Error, Result};
 pub struct Repository {
     /// Git repository.
     inner: git2::Repository,
+    /// Tag prefix.
+    prefix: String,
+    /// Default branch.
+    default_branch: String,
+    /// Forge, if any.
+    forge: Option<Forge>,
 }
 
 // ----------------------------------------------------------------------------
@@ -73,11 +84,48 @@ impl Repository {
     where
         P: AsRef<Path>,
     {
+        let inner = git2::Repository::discover(path)?;
+
+        // Detect forge from the URL of the origin remote, if any
+        let forge = inner
+            .find_remote("origin")
+            .ok()
+            .and_then(|remote| remote.url().and_then(Forge::detect));
+
+        // Resolve default branch, and return repository
+        let default_branch = default_branch(&inner);
         Ok(Self {
-            inner: git2::Repository::discover(path)?,
+            inner,
+            prefix: String::from("v"),
+            default_branch,
+            forge,
         })
     }
 
+    /// Sets the prefix of version tags, which defaults to `v`.
+    pub fn set_prefix<P>(&mut self, prefix: P)
+    where
+        P: Into<String>,
+    {
+        self.prefix = prefix.into();
+    }
+
+    /// Sets the name of the default branch.
+    ///
+    /// By default, the branch `origin/HEAD` points to is used, falling back to
+    /// the `init.defaultBranch` setting, and finally to `master`.
+    pub fn set_default_branch<B>(&mut self, branch: B)
+    where
+        B: Into<String>,
+    {
+        self.default_branch = branch.into();
+    }
+
+    /// Sets the forge, which is detected from the `origin` remote by default.
+    pub fn set_forge(&mut self, forge: Forge) {
+        self.forge = Some(forge);
+    }
+
     /// Stages all files matching the given path specification.
     ///
     /// # Errors
@@ -131,6 +179,84 @@ impl Repository {
         Ok(())
     }
 
+    /// Creates an annotated tag with the given name pointing to `HEAD`.
+    ///
+    /// Like [`Repository::commit`], this method falls back to the `git` command
+    /// line interface, so tags can optionally be signed with the signing key
+    /// configured by the user, which can be a GPG or SSH key, as determined by
+    /// the `gpg.format` and `user.signingKey` settings.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Status`], if `git` exits with a non-zero
+    /// status, including the case where a tag with the given name already
+    /// exists, and [`Error::Io`] if `git` can't be spawned.
+    pub fn tag<N, M>(&self, name: N, message: M, sign: bool) -> Result
+    where
+        N: AsRef<str>,
+        M: AsRef<str>,
+    {
+        let status = Command::new("git")
+            .current_dir(self.path())
+            .args([
+                "tag",
+                if sign { "--sign" } else { "--annotate" },
+                "--cleanup=verbatim", // Preserve markdown formatting
+                "--message",
+                message.as_ref(),
+                name.as_ref(),
+            ])
+            .status()?;
+
+        // Wrap non-zero exit status as error - switch to `ExitStatusError` when
+        // #84908 is stable – https://github.com/rust-lang/rust/issues/84908
+        if !status.success() {
+            return Err(Error::Status(status));
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Deletes the tag with the given name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn delete_tag<N>(&self, name: N) -> Result
+    where
+        N: AsRef<str>,
+    {
+        self.inner.tag_delete(name.as_ref())?;
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Resets `HEAD`, the index and the working tree to the given revision.
+    ///
+    /// This is a hard reset, discarding all uncommitted changes, so callers
+    /// should ensure that the working tree is clean before invoking it.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn reset<S>(&self, spec: S) -> Result
+    where
+        S: AsRef<str>,
+    {
+        let object = self.inner.revparse_single(spec.as_ref())?;
+        self.inner.reset(&object, git2::ResetType::Hard, None)?;
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Creates a branch with the given name from `HEAD` and checks it out.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
     pub fn branch<N>(&self, name: N) -> Result
     where
         N: AsRef<str>,
@@ -213,18 +339,193 @@ impl Repository {
     /// # }
     /// ```
     pub fn on_default_branch(&self) -> Result<bool> {
+        let branch = self.current_branch()?;
+        Ok(branch.is_some_and(|name| name == self.default_branch))
+    }
+
+    /// Returns whether the given commit is reachable from a remote branch.
+    ///
+    /// Only remote-tracking branches are considered, i.e., the state of remotes
+    /// as of the last fetch, so this method doesn't access the network.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn is_pushed(&self, id: &Id) -> Result<bool> {
+        for branch in self.inner.branches(Some(git2::BranchType::Remote))? {
+            let (branch, _) = branch?;
+
+            // Resolve the branch, as `origin/HEAD` is a symbolic reference
+            let reference = branch.get().resolve()?;
+            let Some(target) = reference.target() else {
+                continue;
+            };
+
+            // Check whether the commit is contained in the remote branch
+            if target == **id || self.inner.graph_descendant_of(target, **id)? {
+                return Ok(true);
+            }
+        }
+
+        // Commit is local-only
+        Ok(false)
+    }
+
+    /// Returns whether the tag with the given name exists on any remote.
+    ///
+    /// Tags have no remote-tracking counterpart, so, other than [`is_pushed`],
+    /// this method asks each remote for the tag, which accesses the network.
+    /// If a remote can't be reached, an error is returned, since we can't
+    /// know whether the tag was pushed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the remotes can't be listed, and
+    /// [`Error::Status`] if a remote can't be queried.
+    ///
+    /// [`is_pushed`]: Self::is_pushed
+    pub fn is_tag_pushed<N>(&self, name: N) -> Result<bool>
+    where
+        N: AsRef<str>,
+    {
+        let refname = format!("refs/tags/{}", name.as_ref());
+        for remote in self.inner.remotes()?.iter().flatten() {
+            let output = Command::new("git")
+                .current_dir(self.path())
+                .args(["ls-remote", "--tags", remote, &refname])
+                .output()?;
+
+            // Wrap non-zero exit status as error, e.g., if the remote can't
+            // be reached, since we'd otherwise consider the tag local-only
+            if !output.status.success() {
+                return Err(Error::Status(output.status));
+            }
+
+            // Check whether the remote knows the tag
+            if !output.stdout.is_empty() {
+                return Ok(true);
+            }
+        }
+
+        // Tag is local-only
+        Ok(false)
+    }
+
+    /// Returns the name of the current branch, or nothing if `HEAD` is detached.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ctrl_z_repository::Repository;
+    ///
+    /// // Find and open repository from current directory
+    /// let repo = Repository::open(".")?;
+    /// if let Some(branch) = repo.current_branch()? {
+    ///     println!("On branch {branch}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn current_branch(&self) -> Result<Option<String>> {
         let head = self.inner.head()?;
-        Ok(head.shorthand().filter(|&name| name == "master").is_some())
+        if head.is_branch() {
+            Ok(head.shorthand().map(str::to_string))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the path to the hooks directory.
+    ///
+    /// If `core.hooksPath` is set, it takes precedence, and relative paths are
+    /// resolved against the working directory, exactly like git does. Else,
+    /// hooks are located in the common directory, which is shared by all
+    /// worktrees of the repository.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use ctrl_z_repository::Repository;
+    ///
+    /// // Find and open repository from current directory
+    /// let repo = Repository::open(".")?;
+    /// println!("{}", repo.hooks()?.display());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> Result<PathBuf> {
+        let config = self.inner.config()?;
+        match config.get_path("core.hooksPath") {
+            Ok(path) => Ok(self.path().join(path)),
+            Err(err) if err.code() == git2::ErrorCode::NotFound => {
+                Ok(self.inner.commondir().join("hooks"))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns the character that starts comment lines in commit messages.
+    ///
+    /// If `core.commentChar` is set, it takes precedence, which might also be
+    /// more than one character, as supported by recent versions of git. Else,
+    /// or if it's set to `auto`, which makes git pick a character that isn't
+    /// used in the commit message, which we can't know, `#` is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn comment_char(&self) -> Result<String> {
+        let config = self.inner.config()?;
+        match config.get_string("core.commentChar") {
+            Ok(value) if !value.is_empty() && value != "auto" => Ok(value),
+            Ok(_) => Ok(String::from("#")),
+            Err(err) if err.code() == git2::ErrorCode::NotFound => {
+                Ok(String::from("#"))
+            }
+            Err(err) => Err(err.into()),
+        }
     }
 }
 
 #[allow(clippy::must_use_candidate)]
 impl Repository {
+    /// Returns a reference to the tag prefix.
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns a reference to the default branch.
+    #[inline]
+    pub fn default_branch(&self) -> &str {
+        &self.default_branch
+    }
+
+    /// Returns a reference to the forge, if any.
+    #[inline]
+    pub fn forge(&self) -> Option<&Forge> {
+        self.forge.as_ref()
+    }
+
     /// Returns a reference to the repository path.
-    #[allow(clippy::missing_panics_doc)]
+    ///
+    /// This is the working directory, which is also correct for worktrees, as
+    /// their git directory is located inside the common git directory.
     #[inline]
     pub fn path(&self) -> &Path {
-        self.inner.path().parent().expect("invariant")
+        self.inner.workdir().unwrap_or_else(|| self.inner.path())
     }
 }
 
@@ -240,3 +541,31 @@ impl fmt::Debug for Repository {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Resolves the name of the default branch of the given repository.
+///
+/// If the `origin` remote has a `HEAD`, which is set up when cloning, the
+/// branch it points to is used. Otherwise, we fall back to the branch name
+/// git uses for new repositories, as set in `init.defaultBranch`, and then to
+/// `master`, which is git's own default.
+fn default_branch(inner: &git2::Repository) -> String {
+    let remote = inner.find_reference("refs/remotes/origin/HEAD").ok();
+    let branch = remote.and_then(|reference| {
+        let target = reference.symbolic_target()?;
+        target
+            .strip_prefix("refs/remotes/origin/")
+            .map(str::to_string)
+    });
+
+    // Fall back to the configured default branch for new repositories
+    branch
+        .or_else(|| {
+            let config = inner.config().ok()?;
+            config.get_string("init.defaultBranch").ok()
+        })
+        .unwrap_or_else(|| String::from("master"))
+}
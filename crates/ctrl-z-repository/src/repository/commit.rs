@@ -27,15 +27,18 @@
 
 use std::fmt;
 
+use super::date::Date;
 use super::error::Result;
 use super::id::Id;
 use super::Repository;
 
 mod delta;
 mod iter;
+mod signature;
 
 pub use delta::{Delta, Deltas};
 pub use iter::Commits;
+pub use signature::Signature;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -95,6 +98,36 @@ impl Repository {
 
 // ----------------------------------------------------------------------------
 
+impl Commit<'_> {
+    /// Returns the co-authors of the commit.
+    ///
+    /// Co-authors are denoted by `Co-authored-by` trailers in the commit body,
+    /// which are matched case-insensitively. Malformed trailers are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn co_authors(&self) -> Result<Vec<Signature>> {
+        let Some(body) = self.body() else {
+            return Ok(Vec::new());
+        };
+
+        // Parse trailers, and retain co-authors, sharing the author's time
+        let author = self.author();
+        let iter =
+            parse_trailers(body)?
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    key.eq_ignore_ascii_case("co-authored-by")
+                        .then(|| Signature::parse(&value, &author))
+                        .flatten()
+                });
+        Ok(iter.collect())
+    }
+}
+
 #[allow(clippy::must_use_candidate)]
 impl Commit<'_> {
     /// Returns the commit identifier.
@@ -115,6 +148,24 @@ impl Commit<'_> {
     pub fn body(&self) -> Option<&str> {
         self.inner.body().filter(|body| !body.is_empty())
     }
+
+    /// Returns the commit author.
+    #[inline]
+    pub fn author(&self) -> Signature {
+        self.inner.author().into()
+    }
+
+    /// Returns the committer.
+    #[inline]
+    pub fn committer(&self) -> Signature {
+        self.inner.committer().into()
+    }
+
+    /// Returns the date of the commit, as authored.
+    #[inline]
+    pub fn date(&self) -> Date {
+        self.inner.author().when().into()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -155,6 +206,47 @@ impl fmt::Debug for Commit<'_> {
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Parses the trailers from the given commit message.
+///
+/// Trailers are returned as key-value pairs in the order of their appearance,
+/// with continuation lines of values unfolded into a single line. Note that
+/// git doesn't allow whitespace in trailer keys, but Conventional Commits
+/// defines `BREAKING CHANGE` as a synonym for `BREAKING-CHANGE`, so
+/// we normalize it before parsing, which returns the latter.
+///
+/// # Errors
+///
+/// This method returns [`Error::Git`][] if the operation fails.
+///
+/// [`Error::Git`]: crate::repository::Error::Git
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use ctrl_z_repository::commit::parse_trailers;
+///
+/// // Parse trailers from commit message
+/// let trailers = parse_trailers("Body\n\nBREAKING CHANGE: note")?;
+/// assert_eq!(trailers, [("BREAKING-CHANGE".into(), "note".into())]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_trailers(message: &str) -> Result<Vec<(String, String)>> {
+    // We must add two line feeds to the message, or the trailers would not be
+    // discoverable, since git assumes that we pass the entire commit message
+    let prepared = format!("\n\n{}", normalize(message));
+    let trailers = git2::message_trailers_strs(prepared.as_str())?;
+    Ok(trailers
+        .iter()
+        .map(|(key, value)| {
+            let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            (key.to_string(), value)
+        })
+        .collect())
+}
+
 /// Trims the trailers from the given commit message.
 ///
 /// This function implements the most concise way to remove trailers from the
@@ -168,11 +260,191 @@ impl fmt::Debug for Commit<'_> {
 pub fn trim_trailers(message: &str) -> Result<&str> {
     // We must add two line feeds to the message, or the trailers would not be
     // discoverable, since git assumes that we pass the entire commit message
-    let prepared = format!("\n\n{message}");
+    let normalized = normalize(message);
+    let prepared = format!("\n\n{normalized}");
     let trailers = git2::message_trailers_strs(prepared.as_str())?;
-    if let Some((key, _)) = trailers.iter().next() {
-        Ok(message.split_once(key).map_or(message, |(body, _)| body))
-    } else {
-        Ok(message)
+
+    // Normalization preserves byte offsets, so we can locate the first trailer
+    // in the normalized message and cut the original message at that offset.
+    // Trailers are always part of the final paragraph, so we only search the
+    // lines of the final paragraph, as the key might also appear in the body
+    let Some((key, _)) = trailers.iter().next() else {
+        return Ok(message);
+    };
+    let trimmed = normalized.trim_end();
+    let mut offset = trimmed.rfind("\n\n").map_or(0, |index| index + 2);
+    for line in trimmed[offset..].split_inclusive('\n') {
+        if line.starts_with(key) {
+            return Ok(&message[..offset]);
+        }
+        offset += line.len();
+    }
+
+    // No trailer found, which should not happen
+    Ok(message)
+}
+
+/// Normalizes `BREAKING CHANGE` footers, so git recognizes them as trailers.
+fn normalize(message: &str) -> String {
+    let mut normalized = String::with_capacity(message.len());
+    for line in message.split_inclusive('\n') {
+        match line.strip_prefix("BREAKING CHANGE:") {
+            Some(rest) => {
+                normalized.push_str("BREAKING-CHANGE:");
+                normalized.push_str(rest);
+            }
+            None => normalized.push_str(line),
+        }
+    }
+    normalized
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod parse_trailers {
+        use crate::repository::commit::parse_trailers;
+        use crate::repository::Result;
+
+        #[test]
+        fn handles_trailers() -> Result {
+            let trailers = parse_trailers(
+                "Body\n\nSigned-off-by: Alice <alice@example.com>\n\
+                 Co-authored-by: Bob <bob@example.com>\n",
+            )?;
+            assert_eq!(
+                trailers,
+                [
+                    (
+                        "Signed-off-by".into(),
+                        "Alice <alice@example.com>".into()
+                    ),
+                    ("Co-authored-by".into(), "Bob <bob@example.com>".into()),
+                ]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_continuation_lines() -> Result {
+            let trailers = parse_trailers(
+                "Body\n\nBREAKING-CHANGE: use the\n  new api\n",
+            )?;
+            assert_eq!(
+                trailers,
+                [("BREAKING-CHANGE".into(), "use the new api".into())]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_breaking_change() -> Result {
+            for message in [
+                "Body\n\nBREAKING CHANGE: note",
+                "Body\n\nBREAKING-CHANGE: note",
+                "BREAKING CHANGE: note",
+            ] {
+                assert_eq!(
+                    parse_trailers(message)?,
+                    [("BREAKING-CHANGE".into(), "note".into())]
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_no_trailers() -> Result {
+            assert!(parse_trailers("")?.is_empty());
+            assert!(parse_trailers("Body\n\nMore text")?.is_empty());
+            assert!(parse_trailers("Key: value\n\nMore text")?.is_empty());
+            Ok(())
+        }
+    }
+
+    mod trim_trailers {
+        use crate::repository::commit::trim_trailers;
+        use crate::repository::Result;
+
+        #[test]
+        fn handles_trailers() -> Result {
+            let message = "Body\n\nSigned-off-by: Alice <alice@example.com>\n";
+            assert_eq!(trim_trailers(message)?, "Body\n\n");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_breaking_change() -> Result {
+            let message = "Body\n\nBREAKING CHANGE: note\nRefs: #1\n";
+            assert_eq!(trim_trailers(message)?, "Body\n\n");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_key_in_body() -> Result {
+            let message = "Mentions Signed-off-by in the body\n\n\
+                           Signed-off-by: Alice <alice@example.com>\n";
+            assert_eq!(
+                trim_trailers(message)?,
+                "Mentions Signed-off-by in the body\n\n"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_key_in_previous_paragraph() -> Result {
+            let message = "Signed-off-by: Alice <alice@example.com>\n\n\
+                           More text\n\n\
+                           Signed-off-by: Alice <alice@example.com>\n";
+            assert_eq!(
+                trim_trailers(message)?,
+                "Signed-off-by: Alice <alice@example.com>\n\nMore text\n\n"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_trailing_whitespace() -> Result {
+            let message = "Body\n\nRefs: #1\n\n\n";
+            assert_eq!(trim_trailers(message)?, "Body\n\n");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_no_trailers() -> Result {
+            assert_eq!(
+                trim_trailers("Body\n\nMore text\n")?,
+                "Body\n\nMore text\n"
+            );
+            assert_eq!(trim_trailers("")?, "");
+            Ok(())
+        }
+    }
+
+    mod normalize {
+        use crate::repository::commit::normalize;
+
+        #[test]
+        fn handles_breaking_change() {
+            assert_eq!(
+                normalize("Body\n\nBREAKING CHANGE: note\n"),
+                "Body\n\nBREAKING-CHANGE: note\n"
+            );
+        }
+
+        #[test]
+        fn handles_breaking_change_within_line() {
+            let message = "Body with BREAKING CHANGE: inside\n";
+            assert_eq!(normalize(message), message);
+        }
+
+        #[test]
+        fn handles_byte_offsets() {
+            let message = "Body\n\nBREAKING CHANGE: note";
+            assert_eq!(normalize(message).len(), message.len());
+        }
     }
 }
@@ -42,8 +42,6 @@ pub struct Commits<'a> {
     repository: &'a Repository,
     /// Git revision walk.
     revwalk: git2::Revwalk<'a>,
-    /// End of range.
-    end: Option<Id>,
 }
 
 // ----------------------------------------------------------------------------
@@ -54,7 +52,10 @@ impl Repository {
     /// Creates an iterator over the commits in the repository.
     ///
     /// This method accepts a range of commit identifiers for iteration. If no
-    /// bounds are given, iteration ranges from `HEAD` until the end.
+    /// bounds are given, iteration ranges from `HEAD` until the end. The end
+    /// of the range is excluded together with all of its ancestors, which is
+    /// equivalent to git's `end..start` notation, so merged branches are
+    /// correctly taken into account.
     ///
     /// # Errors
     ///
@@ -88,35 +89,33 @@ impl Repository {
 
         // Determine start and end of range - note that the range is exclusive
         // by default, allowing to easily determine commits between two tags
-        let end = match (range.start_bound(), range.end_bound()) {
+        match (range.start_bound(), range.end_bound()) {
             // .. - all commits from HEAD
             (Bound::Unbounded, Bound::Unbounded) => {
                 revwalk.push_head()?;
-                None
             }
             // ..end - commits until end (excluded)
             (Bound::Unbounded, Bound::Excluded(end)) => {
                 revwalk.push_head()?;
-                Some(*end)
+                revwalk.hide(**end)?;
             }
             // start.. - commits from start onwards
             (Bound::Included(start), Bound::Unbounded) => {
                 revwalk.push(**start)?;
-                None
             }
             // start..end - commits between start and end
             (Bound::Included(start), Bound::Excluded(end)) => {
                 revwalk.push(**start)?;
-                Some(*end)
+                revwalk.hide(**end)?;
             }
             // Unsupported range bound
             (Bound::Excluded(_), _) | (_, Bound::Included(_)) => {
                 return Err(Error::Bound);
             }
-        };
+        }
 
         // Return iterator over commits
-        Ok(Commits { repository: self, revwalk, end })
+        Ok(Commits { repository: self, revwalk })
     }
 }
 
@@ -129,16 +128,88 @@ impl<'a> Iterator for Commits<'a> {
 
     /// Returns the next commit.
     fn next(&mut self) -> Option<Self::Item> {
-        let id = match self.revwalk.next()? {
-            Ok(id) => id,
-            Err(err) => return Some(Err(err.into())),
-        };
-
-        // Return next commit, if we haven't reached the end of the range
-        if self.end.as_deref() == Some(&id) {
-            None
-        } else {
-            Some(self.repository.get(id))
+        match self.revwalk.next()? {
+            Ok(id) => Some(self.repository.get(id)),
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod commits {
+        use std::error::Error;
+        use std::path::Path;
+        use std::process::Command;
+        use tempfile::TempDir;
+
+        use crate::repository::Repository;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Runs git with the given arguments in the given directory.
+        fn git(root: &Path, args: &[&str]) -> Result {
+            let status = Command::new("git")
+                .current_dir(root)
+                .args(["-c", "user.name=Alice", "-c", "user.email=a@b.c"])
+                .args(["-c", "commit.gpgsign=false"])
+                .args(args)
+                .status()?;
+            assert!(status.success());
+            Ok(())
+        }
+
+        /// Creates a repository, where `main` and `topic` diverge from the
+        /// initial commit, and `topic` is merged into `merge`, a branch off
+        /// `main`, and returns the temporary directory.
+        fn setup() -> Result<TempDir> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            git(root, &["init", "--quiet", "--initial-branch", "main"])?;
+            git(root, &["commit", "--quiet", "--allow-empty", "-m", "a"])?;
+            git(root, &["checkout", "--quiet", "-b", "topic"])?;
+            git(root, &["commit", "--quiet", "--allow-empty", "-m", "c"])?;
+            git(root, &["checkout", "--quiet", "main"])?;
+            git(root, &["commit", "--quiet", "--allow-empty", "-m", "b"])?;
+            git(root, &["checkout", "--quiet", "-b", "merge"])?;
+            git(root, &["merge", "--quiet", "--no-ff", "-m", "m", "topic"])?;
+            Ok(dir)
+        }
+
+        /// Returns the summaries of the commits in the given range.
+        fn summaries(
+            root: &Path, start: &str, end: &str,
+        ) -> Result<Vec<String>> {
+            let repository = Repository::open(root)?;
+            let start = repository.find(start)?.id();
+            let end = repository.find(end)?.id();
+            let mut summaries = Vec::new();
+            for commit in repository.commits(start..end)? {
+                summaries.push(commit?.summary().to_string());
+            }
+            Ok(summaries)
+        }
+
+        #[test]
+        fn handles_diverged_end() -> Result {
+            let dir = setup()?;
+            assert_eq!(summaries(dir.path(), "main", "topic")?, ["b"]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_merged_end() -> Result {
+            let dir = setup()?;
+            let mut value = summaries(dir.path(), "merge", "main")?;
+            value.sort();
+            assert_eq!(value, ["c", "m"]);
+            Ok(())
         }
     }
 }
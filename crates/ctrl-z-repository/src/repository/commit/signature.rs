@@ -0,0 +1,131 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Signature.
+
+use std::fmt;
+
+use crate::repository::date::Date;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Signature.
+///
+/// Signatures identify the author or committer of a commit, as well as its
+/// co-authors, which are denoted by `Co-authored-by` trailers. Since those
+/// trailers carry no timestamp, co-authors share the time of the author.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    /// Name.
+    name: String,
+    /// Email address.
+    email: String,
+    /// Time in seconds since the Unix epoch.
+    time: i64,
+    /// Time zone offset in minutes.
+    offset: i32,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Signature {
+    /// Attempts to parse a signature in `Name <email>` format.
+    ///
+    /// The time is taken from the given signature, as the format doesn't
+    /// include one. This is used for parsing `Co-authored-by` trailers.
+    pub(crate) fn parse(value: &str, signature: &Signature) -> Option<Self> {
+        let (name, email) = value.trim().strip_suffix('>')?.split_once('<')?;
+        let (name, email) = (name.trim(), email.trim());
+        (!name.is_empty() && !email.is_empty()).then(|| Self {
+            name: name.to_string(),
+            email: email.to_string(),
+            ..*signature
+        })
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl Signature {
+    /// Returns a reference to the name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns a reference to the email address.
+    #[inline]
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    /// Returns the time in seconds since the Unix epoch.
+    #[inline]
+    pub fn time(&self) -> i64 {
+        self.time
+    }
+
+    /// Returns the time zone offset in minutes.
+    #[inline]
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// Returns the date in the time zone of the signature.
+    #[inline]
+    pub fn date(&self) -> Date {
+        git2::Time::new(self.time, self.offset).into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl From<git2::Signature<'_>> for Signature {
+    /// Creates a signature from a Git signature.
+    fn from(signature: git2::Signature<'_>) -> Self {
+        let when = signature.when();
+        Self {
+            name: String::from_utf8_lossy(signature.name_bytes()).into_owned(),
+            email: String::from_utf8_lossy(signature.email_bytes())
+                .into_owned(),
+            time: when.seconds(),
+            offset: when.offset_minutes(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Signature {
+    /// Formats the signature for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)
+    }
+}
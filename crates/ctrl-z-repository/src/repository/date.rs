@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Date.
+
+use std::fmt;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Date.
+///
+/// Dates are derived from the timestamps of commits, and are expressed in the
+/// time zone of the author, which is what a reader of a changelog expects. As
+/// we only need dates for display, we don't depend on a full-blown date and
+/// time library, but convert timestamps to the proleptic Gregorian calendar.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    /// Year.
+    year: i64,
+    /// Month, starting at 1.
+    month: u8,
+    /// Day of month, starting at 1.
+    day: u8,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+#[allow(clippy::must_use_candidate)]
+impl Date {
+    /// Returns the year.
+    #[inline]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the month, starting at 1.
+    #[inline]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of month, starting at 1.
+    #[inline]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl From<git2::Time> for Date {
+    /// Creates a date from a Git timestamp, honoring its time zone offset.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from(time: git2::Time) -> Self {
+        let seconds = time.seconds() + i64::from(time.offset_minutes()) * 60;
+
+        // Convert days since the Unix epoch to a civil date, using the
+        // algorithm by Howard Hinnant, which shifts the year to start in March
+        let days = seconds.div_euclid(86_400) + 719_468;
+        let era = days.div_euclid(146_097);
+        let doe = days - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self { year, month, day }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Date {
+    /// Formats the date for display, in ISO 8601 format.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from {
+        use crate::repository::date::Date;
+
+        /// Creates a date from the given timestamp and offset in minutes.
+        fn date(seconds: i64, offset: i32) -> String {
+            Date::from(git2::Time::new(seconds, offset)).to_string()
+        }
+
+        #[test]
+        fn handles_timestamps() {
+            for (seconds, expected) in [
+                (0, "1970-01-01"),
+                (86_399, "1970-01-01"),
+                (86_400, "1970-01-02"),
+                (951_782_400, "2000-02-29"),
+                (951_868_800, "2000-03-01"),
+                (1_709_164_800, "2024-02-29"),
+                (1_735_689_599, "2024-12-31"),
+                (1_735_689_600, "2025-01-01"),
+                (-1, "1969-12-31"),
+                (-86_400, "1969-12-31"),
+                (-86_401, "1969-12-30"),
+            ] {
+                assert_eq!(date(seconds, 0), expected, "{seconds}");
+            }
+        }
+
+        #[test]
+        fn handles_offsets() {
+            // 2024-12-31T23:30:00Z is already 2025 east of UTC
+            assert_eq!(date(1_735_687_800, 0), "2024-12-31");
+            assert_eq!(date(1_735_687_800, 60), "2025-01-01");
+            assert_eq!(date(1_735_687_800, -60), "2024-12-31");
+
+            // 2025-01-01T00:30:00Z is still 2024 west of UTC
+            assert_eq!(date(1_735_691_400, -60), "2024-12-31");
+        }
+
+        #[test]
+        fn handles_accessors() {
+            let date = Date::from(git2::Time::new(951_782_400, 0));
+            assert_eq!(date.year(), 2000);
+            assert_eq!(date.month(), 2);
+            assert_eq!(date.day(), 29);
+        }
+    }
+
+    mod fmt {
+        use crate::repository::date::Date;
+
+        #[test]
+        fn handles_padding() {
+            let date = Date { year: 987, month: 1, day: 2 };
+            assert_eq!(date.to_string(), "0987-01-02");
+        }
+    }
+}
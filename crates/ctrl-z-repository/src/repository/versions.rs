@@ -27,12 +27,13 @@
 
 use semver::Version;
 use std::collections::btree_map::{Iter, Range};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::iter::Rev;
 use std::ops::RangeBounds;
 
 use super::commit::Commits;
+use super::date::Date;
 use super::error::{Error, Result};
 use super::id::Id;
 use super::Repository;
@@ -46,12 +47,21 @@ use super::Repository;
 /// This data type manages the existing versions in a given repository. Versions
 /// are ordered chronologically, so iteration and range queries are simple. Each
 /// version is mapped to the identifier of its corresponding commit, so commits
-/// can be obtained to query for changes between two versions.
+/// can be obtained to query for changes between two versions. Additionally,
+/// the release date of each version is recorded, which is the date of the
+/// tagger for annotated tags, and the date of the commit for lightweight tags,
+/// as well as whether its tag is annotated.
 pub struct Versions<'a> {
     /// Repository.
     repository: &'a Repository,
+    /// Tag prefix.
+    prefix: String,
     /// Versions and their corresponding commit identifiers.
     tags: BTreeMap<Version, Id>,
+    /// Versions and their release dates.
+    dates: BTreeMap<Version, Date>,
+    /// Versions with annotated tags.
+    annotated: BTreeSet<Version>,
 }
 
 // ----------------------------------------------------------------------------
@@ -63,8 +73,9 @@ impl Repository {
     ///
     /// This method only extracts the tags matching semantic version specifiers
     /// from the given repository, and returns a version set. Tags must abide
-    /// to the `vMAJOR.MINOR.PATCH` format, but can include pre-release and
-    /// build suffixes as well. Each tag is parsed as a [`Version`].
+    /// to the `vMAJOR.MINOR.PATCH` format, where `v` is the configured prefix,
+    /// but can include pre-release and build suffixes as well. Each tag is
+    /// parsed as a [`Version`].
     ///
     /// # Errors
     ///
@@ -72,21 +83,75 @@ impl Repository {
     ///
     /// [`Error::Git`]: crate::repository::Error::Git
     pub fn versions(&self) -> Result<Versions<'_>> {
-        let tags = self.inner.tag_names(Some("v[0-9]*.[0-9]*.[0-9]**"))?;
-        let iter = tags.iter().flatten().map(|name| {
-            let version = name.trim_start_matches('v').parse()?;
-            Ok((version, self.find(name)?.id()))
-        });
+        self.versions_with_prefix(&self.prefix)
+    }
+
+    /// Returns the version set of the package with the given name.
+    ///
+    /// When packages are versioned independently, each package has its own
+    /// series of tags, which must abide to the `name@MAJOR.MINOR.PATCH` format,
+    /// e.g., `ctrl-z-project@0.3.1`, where `name` is the name of the package.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn package_versions<N>(&self, name: N) -> Result<Versions<'_>>
+    where
+        N: AsRef<str>,
+    {
+        self.versions_with_prefix(&format!("{}@", name.as_ref()))
+    }
+
+    /// Returns the version set of all tags with the given prefix.
+    #[allow(clippy::missing_panics_doc)]
+    fn versions_with_prefix(&self, prefix: &str) -> Result<Versions<'_>> {
+        let pattern = format!("{prefix}[0-9]*.[0-9]*.[0-9]**");
+        let tags = self.inner.tag_names(Some(&pattern))?;
+        let mut versions = Versions {
+            repository: self,
+            prefix: prefix.to_string(),
+            tags: BTreeMap::new(),
+            dates: BTreeMap::new(),
+            annotated: BTreeSet::new(),
+        };
+
+        // Resolve each tag to its commit and release date, preferring the
+        // tagger's time of annotated tags over the time of the commit
+        for name in tags.iter().flatten() {
+            let version = name.strip_prefix(prefix).expect("invariant");
+            let version: Version = version.parse()?;
+            let object = self.inner.revparse_single(name)?;
+            let commit = object.peel_to_commit()?;
+            let date = match object.as_tag().and_then(git2::Tag::tagger) {
+                Some(tagger) => tagger.when().into(),
+                None => commit.time().into(),
+            };
+            if object.as_tag().is_some() {
+                versions.annotated.insert(version.clone());
+            }
+            versions.tags.insert(version.clone(), commit.id().into());
+            versions.dates.insert(version, date);
+        }
 
-        // Collect and return version set
-        let tags = iter.collect::<Result<_>>()?;
-        Ok(Versions { repository: self, tags })
+        // Return version set
+        Ok(versions)
     }
 }
 
 // ----------------------------------------------------------------------------
 
 impl Versions<'_> {
+    /// Returns the tag name for the given version.
+    ///
+    /// Note that this method doesn't check whether the version exists, as it's
+    /// also used to derive the tag name of the next version.
+    #[must_use]
+    pub fn tag(&self, version: &Version) -> String {
+        format!("{}{version}", self.prefix)
+    }
+
     /// Returns the commit identifier for the given version.
     #[inline]
     #[must_use]
@@ -94,6 +159,23 @@ impl Versions<'_> {
         self.tags.get(version)
     }
 
+    /// Returns the release date for the given version.
+    #[inline]
+    #[must_use]
+    pub fn date(&self, version: &Version) -> Option<Date> {
+        self.dates.get(version).copied()
+    }
+
+    /// Returns whether the tag of the given version is annotated.
+    ///
+    /// Release tags should be annotated, since lightweight tags carry neither
+    /// a message, nor a tagger, nor a signature, so they can't be verified.
+    #[inline]
+    #[must_use]
+    pub fn is_annotated(&self, version: &Version) -> bool {
+        self.annotated.contains(version)
+    }
+
     /// Returns whether the version set contains the given version.
     #[inline]
     #[must_use]
@@ -111,40 +193,171 @@ impl Versions<'_> {
         self.tags.range(range)
     }
 
-    // @todo naming... - or commits iter? repository.version?
+    /// Returns the latest version reachable from `HEAD`.
+    ///
+    /// Like `git describe`, only versions whose tags are ancestors of `HEAD`
+    /// are considered, so on a maintenance branch, e.g., `release/1.x`, the
+    /// latest version is the latest version of that line, and not a version
+    /// of a later line that was released from another branch.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn latest(&self) -> Result<Option<&Version>> {
+        let head = self.head()?;
+        self.find(&head, self.tags.keys().rev(), false)
+    }
+
+    /// Returns the version before the given version.
+    ///
+    /// The version before a stable version must be a stable version, so that
+    /// pre-release versions are skipped, unless the given version is itself a
+    /// pre-release version. Moreover, the version before must be an ancestor
+    /// of the given version, so versions released from other branches, e.g.,
+    /// patch releases of older lines, are skipped. Note that the given version
+    /// doesn't need to exist, in which case ancestry is determined from `HEAD`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn previous(&self, version: &Version) -> Result<Option<&Version>> {
+        let start = match self.tags.get(version) {
+            Some(id) => *id,
+            None => self.head()?,
+        };
+
+        // Find the latest ancestor among the versions before the given version
+        let iter = self.tags.range(..version).rev().map(|(version, _)| version);
+        self.find(&start, iter, version.pre.is_empty())
+    }
+
+    /// Creates an iterator over the commits of the given version.
+    ///
+    /// The iterator emits all commits between the given version and the one
+    /// before it, or all commits until the initial commit for the first one.
+    /// Pre-release versions are skipped when looking for the version before a
+    /// stable version, so the commits of a stable version include all commits
+    /// of its pre-release versions, e.g., `1.4.0-rc.1` and `1.4.0-rc.2`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Version`], if the version doesn't exist,
+    /// and [`Error::Git`] if the operation fails.
     pub fn commits(&self, version: &Version) -> Result<Commits<'_>> {
-        if !self.tags.contains_key(version) {
+        let Some(start) = self.tags.get(version) else {
             return Err(Error::Version);
-        }
+        };
 
-        let mut iter = self.tags.range(..=version).rev();
-        if let Some((_, start)) = iter.next() {
-            if let Some((_, end)) = iter.next() {
-                self.repository.commits(start..end)
-            } else {
-                self.repository.commits(start..)
-            }
+        // Return commits between the given version and the one before it
+        let prev = self.previous(version)?.and_then(|prev| self.tags.get(prev));
+        if let Some(end) = prev {
+            self.repository.commits(start..end)
         } else {
-            Err(Error::Version)
+            self.repository.commits(start..)
         }
     }
 
     /// Creates an iterator over unreleased commits in the repository.
+    ///
+    /// Unreleased commits are all commits after the latest stable version that
+    /// is reachable from `HEAD`. Pre-release versions are not considered to be
+    /// released, which ensures that stable versions aggregate all changes of
+    /// their pre-release versions.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
     pub fn unreleased(&self) -> Result<Commits<'_>> {
-        let mut iter = self.tags.range(..).rev();
-        if let Some((_, end)) = iter.next() {
+        if let Some(end) = self.base()?.and_then(|base| self.tags.get(base)) {
             self.repository.commits(..end)
         } else {
             self.repository.commits(..)
         }
     }
 
+    /// Returns the email addresses of all contributors before the given version.
+    ///
+    /// Contributors are the authors and co-authors of all commits reachable
+    /// from the version before the given version, or, if no version is given,
+    /// from the latest stable version reachable from `HEAD`, i.e., before all
+    /// unreleased commits. Email addresses are lowercased, as they are compared
+    /// case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn contributors(
+        &self, version: Option<&Version>,
+    ) -> Result<BTreeSet<String>> {
+        let prev = match version {
+            Some(version) => self.previous(version)?,
+            None => self.base()?,
+        };
+
+        // Collect authors and co-authors of all commits before the version
+        let mut emails = BTreeSet::new();
+        if let Some(start) = prev.and_then(|prev| self.tags.get(prev)) {
+            for commit in self.repository.commits(start..)? {
+                let commit = commit?;
+                emails.insert(commit.author().email().to_lowercase());
+                for co_author in commit.co_authors()? {
+                    emails.insert(co_author.email().to_lowercase());
+                }
+            }
+        }
+
+        // Return email addresses
+        Ok(emails)
+    }
+
     /// Creates an iterator over the version set.
     pub fn iter(&self) -> impl Iterator<Item = (&Version, &Id)> {
         self.into_iter()
     }
 }
 
+impl Versions<'_> {
+    /// Returns the latest stable version reachable from `HEAD`.
+    ///
+    /// This is the base of the next release, i.e., all commits after it are
+    /// considered to be unreleased.
+    fn base(&self) -> Result<Option<&Version>> {
+        let head = self.head()?;
+        self.find(&head, self.tags.keys().rev(), true)
+    }
+
+    /// Returns the first of the given versions whose tag is an ancestor of, or
+    /// identical to, the given commit, optionally skipping pre-releases.
+    fn find<'a, I>(
+        &self, start: &Id, versions: I, stable: bool,
+    ) -> Result<Option<&'a Version>>
+    where
+        I: IntoIterator<Item = &'a Version>,
+    {
+        let inner = &self.repository.inner;
+        for version in versions {
+            if stable && !version.pre.is_empty() {
+                continue;
+            }
+
+            // Check whether the tag is reachable from the given commit
+            let id = self.tags.get(version).expect("invariant");
+            if id == start || inner.graph_descendant_of(**start, **id)? {
+                return Ok(Some(version));
+            }
+        }
+
+        // No version is reachable
+        Ok(None)
+    }
+
+    /// Returns the identifier of the commit `HEAD` points to.
+    fn head(&self) -> Result<Id> {
+        let head = self.repository.inner.head()?;
+        Ok(head.peel_to_commit()?.id().into())
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -165,7 +378,10 @@ impl fmt::Debug for Versions<'_> {
     /// Formats the version set for debugging.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Versions")
+            .field("prefix", &self.prefix)
             .field("tags", &self.tags)
+            .field("dates", &self.dates)
+            .field("annotated", &self.annotated)
             .finish()
     }
 }
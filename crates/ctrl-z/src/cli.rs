@@ -31,14 +31,19 @@ use clap::Parser;
 use std::env;
 use std::path::PathBuf;
 
+use ctrl_z_project::workspace::writer::{Writable, Writer};
 use ctrl_z_project::{Manifest, Workspace};
-use ctrl_z_repository::Repository;
+use ctrl_z_repository::{Forge, Repository};
 
 mod command;
+mod config;
 mod error;
+mod format;
 
 pub use command::{Command, Commands};
-pub use error::Result;
+pub use config::{Config, Versioning};
+pub use error::{Error, Result};
+pub use format::Format;
 
 use crate::Context;
 
@@ -68,6 +73,9 @@ pub struct Cli {
     /// Working directory.
     #[arg(short, long, value_parser = valid, default_value = ".")]
     pub directory: PathBuf,
+    /// Output format.
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub format: Format,
     /// Commands.
     #[command(subcommand)]
     command: Commands,
@@ -78,14 +86,34 @@ pub struct Cli {
 // ----------------------------------------------------------------------------
 
 impl Cli {
-    pub fn execute<T>(self, repository: Repository, workspace: Workspace<T>)
+    pub fn execute<T>(self, mut repository: Repository, workspace: Workspace<T>)
     where
         T: Manifest,
+        Writer<T>: Writable,
     {
-        match self.command.execute(Context { repository, workspace }) {
+        // Read configuration and apply repository settings, then execute the
+        // command with the context, printing the error in case of failure
+        let format = self.format;
+        let result = Config::read(workspace.path()).and_then(|config| {
+            repository.set_prefix(&config.repository.tag_prefix);
+            if let Some(branch) = &config.repository.branch {
+                repository.set_default_branch(branch);
+            }
+            if let Some(url) = &config.repository.url {
+                let kind = config.repository.forge;
+                repository.set_forge(Forge::new(kind, url));
+            }
+            self.command.execute(Context {
+                repository,
+                workspace,
+                config,
+                format,
+            })
+        });
+        match result {
             Ok(()) => std::process::exit(0),
             Err(err) => {
-                eprintln!("Error: {}", err);
+                eprintln!("Error: {err}");
                 std::process::exit(1)
             }
         }
@@ -27,11 +27,13 @@
 
 use clap::Subcommand;
 
+use ctrl_z_project::workspace::writer::{Writable, Writer};
 use ctrl_z_project::Manifest;
 
 use crate::cli::Result;
 use crate::Context;
 
+mod hooks;
 mod list;
 mod validate;
 mod version;
@@ -56,6 +58,11 @@ where
 /// Commands.
 #[derive(Debug, Subcommand)]
 pub enum Commands {
+    /// Git hook management.
+    Hooks {
+        #[command(subcommand)]
+        command: hooks::Commands,
+    },
     /// List the names of all packages in topological order.
     List(list::Arguments),
     /// Validation and linting.
@@ -77,10 +84,12 @@ pub enum Commands {
 impl<T> Command<T> for Commands
 where
     T: Manifest,
+    Writer<T>: Writable,
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
         match self {
+            Commands::Hooks { command } => command.execute(context),
             Commands::List(args) => args.execute(context),
             Commands::Version { command } => command.execute(context),
             Commands::Validate { command } => command.execute(context),
@@ -0,0 +1,163 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Git hooks.
+
+use clap::Subcommand;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use ctrl_z_project::Manifest;
+
+use crate::cli::{Command, Result};
+use crate::Context;
+
+mod install;
+mod uninstall;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Marker identifying hooks that are managed by us.
+const MARKER: &str = "# Managed by ctrl-z";
+
+/// Hook validating the commit message.
+const COMMIT_MSG: &str = r#"#!/bin/sh
+# Managed by ctrl-z
+
+exec ctrl-z validate commit "$1"
+"#;
+
+/// Hook validating the commit messages of all commits that are pushed.
+const PRE_PUSH: &str = r#"#!/bin/sh
+# Managed by ctrl-z
+
+zero=$(git hash-object --stdin </dev/null | tr '0-9a-f' '0')
+while read -r local_ref local_oid remote_ref remote_oid; do
+  if [ "$local_oid" = "$zero" ]; then
+    continue
+  fi
+
+  # Validate all commits that are not yet known to the remote - for new
+  # branches, start from the parent of the oldest commit not on the remote
+  if [ "$remote_oid" = "$zero" ]; then
+    base=$(git rev-list "$local_oid" --not --remotes="$1" | tail -n 1)
+    if [ -z "$base" ]; then
+      continue
+    elif git rev-parse -q --verify "$base^" >/dev/null; then
+      range="$base^..$local_oid"
+    else
+      range="$local_oid"
+    fi
+  else
+    range="$remote_oid..$local_oid"
+  fi
+  ctrl-z validate commits "$range" || exit 1
+done
+"#;
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Git hooks.
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Install git hooks.
+    Install(install::Arguments),
+    /// Uninstall git hooks.
+    Uninstall(uninstall::Arguments),
+}
+
+// ----------------------------------------------------------------------------
+
+/// Git hook.
+#[derive(Clone, Copy, Debug)]
+enum Hook {
+    /// Hook invoked after the commit message was edited.
+    CommitMsg,
+    /// Hook invoked before commits are pushed.
+    PrePush,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Hook {
+    /// All hooks, in the order they are installed.
+    const ALL: [Hook; 2] = [Hook::CommitMsg, Hook::PrePush];
+
+    /// Returns the file name of the hook.
+    fn name(self) -> &'static str {
+        match self {
+            Hook::CommitMsg => "commit-msg",
+            Hook::PrePush => "pre-push",
+        }
+    }
+
+    /// Returns the script of the hook.
+    fn script(self) -> &'static str {
+        match self {
+            Hook::CommitMsg => COMMIT_MSG,
+            Hook::PrePush => PRE_PUSH,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Commands
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    fn execute(&self, context: Context<T>) -> Result {
+        match self {
+            Commands::Install(args) => args.execute(context),
+            Commands::Uninstall(args) => args.execute(context),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns whether the hook at the given path exists and is managed by us.
+///
+/// Hooks that don't exist are reported as `None`, since they can be safely
+/// installed, while foreign hooks must not be touched unless forced.
+fn is_managed(path: &Path) -> Result<Option<bool>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content.lines().any(|line| line == MARKER))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
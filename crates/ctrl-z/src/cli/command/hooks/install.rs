@@ -0,0 +1,231 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Install git hooks.
+
+use clap::Args;
+use cliclack::log::success;
+use std::fs;
+
+use ctrl_z_project::Manifest;
+
+use crate::cli::{Command, Error, Result};
+use crate::Context;
+
+use super::{is_managed, Hook};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Install git hooks.
+#[derive(Args, Debug)]
+pub struct Arguments {
+    /// Overwrite existing hooks not managed by ctrl-z.
+    #[arg(short, long)]
+    force: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Arguments
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    fn execute(&self, context: Context<T>) -> Result {
+        let hooks = context.repository.hooks()?;
+        fs::create_dir_all(&hooks)?;
+
+        // Check all hooks before writing any of them, so we don't end up with
+        // a partial installation when a foreign hook is encountered
+        for hook in Hook::ALL {
+            let path = hooks.join(hook.name());
+            if is_managed(&path)? == Some(false) && !self.force {
+                return Err(Error::Hook(path));
+            }
+        }
+
+        // Write hooks and make them executable, as git ignores them otherwise
+        for hook in Hook::ALL {
+            let path = hooks.join(hook.name());
+            fs::write(&path, hook.script())?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+            }
+            success(format!("Installed {}", path.display()))?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod execute {
+        use std::error::Error;
+        use std::fs;
+        use std::path::Path;
+        use std::process;
+        use tempfile::TempDir;
+
+        use ctrl_z_project::{Cargo, Workspace};
+        use ctrl_z_repository::Repository;
+
+        use crate::cli::command::hooks::install::Arguments;
+        use crate::cli::command::hooks::MARKER;
+        use crate::cli::{self, Command, Config, Format};
+        use crate::Context;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Runs git with the given arguments in the given directory.
+        fn git(root: &Path, args: &[&str]) -> Result {
+            let status = process::Command::new("git")
+                .current_dir(root)
+                .args(["-c", "user.name=Alice", "-c", "user.email=a@b.c"])
+                .args(["-c", "commit.gpgsign=false"])
+                .args(args)
+                .status()?;
+            assert!(status.success());
+            Ok(())
+        }
+
+        /// Creates a repository with a package and a foreign `commit-msg`
+        /// hook, if requested, and returns the temporary directory.
+        fn setup(foreign: bool) -> Result<TempDir> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            fs::write(
+                root.join("Cargo.toml"),
+                "[package]\nname = \"core\"\nversion = \"1.0.0\"\n",
+            )?;
+            git(root, &["init", "--quiet"])?;
+            if foreign {
+                fs::create_dir_all(root.join(".git/hooks"))?;
+                fs::write(root.join(".git/hooks/commit-msg"), "#!/bin/sh\n")?;
+            }
+            Ok(dir)
+        }
+
+        /// Installs the hooks of the repository in the given directory.
+        fn install(root: &Path, force: bool) -> cli::Result {
+            let context = Context {
+                repository: Repository::open(root)?,
+                workspace: Workspace::<Cargo>::read(root.join("Cargo.toml"))?,
+                config: Config::default(),
+                format: Format::Text,
+            };
+            Arguments { force }.execute(context)
+        }
+
+        /// Asserts that the hooks in the given directory are managed by us.
+        fn assert_managed(hooks: &Path) -> Result {
+            for name in ["commit-msg", "pre-push"] {
+                let content = fs::read_to_string(hooks.join(name))?;
+                assert!(content.lines().any(|line| line == MARKER));
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    let mode = fs::metadata(hooks.join(name))?.permissions();
+                    assert_eq!(mode.mode() & 0o111, 0o111);
+                }
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_install() -> Result {
+            let dir = setup(false)?;
+            install(dir.path(), false)?;
+            assert_managed(&dir.path().join(".git/hooks"))?;
+
+            // Reinstalling managed hooks must not require force
+            install(dir.path(), false)?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_force() -> Result {
+            let dir = setup(true)?;
+            install(dir.path(), true)?;
+            assert_managed(&dir.path().join(".git/hooks"))?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_hooks_path() -> Result {
+            let dir = setup(false)?;
+            git(dir.path(), &["config", "core.hooksPath", ".githooks"])?;
+            install(dir.path(), false)?;
+            assert_managed(&dir.path().join(".githooks"))?;
+            assert!(!dir.path().join(".git/hooks/pre-push").exists());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_worktree() -> Result {
+            let dir = setup(false)?;
+            let root = dir.path();
+            git(root, &["add", "Cargo.toml"])?;
+            git(root, &["commit", "--quiet", "-m", "chore: initial commit"])?;
+            git(root, &["worktree", "add", "--quiet", "tree"])?;
+
+            // Hooks are shared by all worktrees, and live in the common dir
+            install(&root.join("tree"), false)?;
+            assert_managed(&root.join(".git/hooks"))?;
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_unmanaged_hook() -> Result {
+            let dir = setup(true)?;
+            let hooks = dir.path().join(".git/hooks");
+            assert!(matches!(
+                install(dir.path(), false),
+                Err(cli::Error::Hook(path)) if path.ends_with("commit-msg")
+            ));
+
+            // Nothing must be written, so there's no partial installation
+            assert_eq!(
+                fs::read_to_string(hooks.join("commit-msg"))?,
+                "#!/bin/sh\n"
+            );
+            assert!(!hooks.join("pre-push").exists());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Uninstall git hooks.
+
+use clap::Args;
+use cliclack::log::{success, warning};
+use std::fs;
+
+use ctrl_z_project::Manifest;
+
+use crate::cli::{Command, Result};
+use crate::Context;
+
+use super::{is_managed, Hook};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Uninstall git hooks.
+#[derive(Args, Debug)]
+pub struct Arguments {
+    /// Remove existing hooks not managed by ctrl-z.
+    #[arg(short, long)]
+    force: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Arguments
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    fn execute(&self, context: Context<T>) -> Result {
+        let hooks = context.repository.hooks()?;
+        for hook in Hook::ALL {
+            let path = hooks.join(hook.name());
+
+            // Only remove hooks that are managed by us, unless forced, and
+            // leave foreign hooks untouched, but let the user know about it
+            match is_managed(&path)? {
+                None => {}
+                Some(false) if !self.force => {
+                    warning(format!("Skipped {}", path.display()))?;
+                }
+                Some(_) => {
+                    fs::remove_file(&path)?;
+                    success(format!("Removed {}", path.display()))?;
+                }
+            }
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod execute {
+        use std::error::Error;
+        use std::fs;
+        use std::path::Path;
+        use std::process;
+        use tempfile::TempDir;
+
+        use ctrl_z_project::{Cargo, Workspace};
+        use ctrl_z_repository::Repository;
+
+        use crate::cli::command::hooks::uninstall::Arguments;
+        use crate::cli::command::hooks::{COMMIT_MSG, PRE_PUSH};
+        use crate::cli::{self, Command, Config, Format};
+        use crate::Context;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Creates a repository with a package, a managed `pre-push` hook and
+        /// a `commit-msg` hook, which is foreign, if requested, and returns
+        /// the temporary directory.
+        fn setup(foreign: bool) -> Result<TempDir> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            fs::write(
+                root.join("Cargo.toml"),
+                "[package]\nname = \"core\"\nversion = \"1.0.0\"\n",
+            )?;
+            let status = process::Command::new("git")
+                .current_dir(root)
+                .args(["init", "--quiet"])
+                .status()?;
+            assert!(status.success());
+
+            // Write hooks
+            let hooks = root.join(".git/hooks");
+            fs::create_dir_all(&hooks)?;
+            let script = if foreign { "#!/bin/sh\n" } else { COMMIT_MSG };
+            fs::write(hooks.join("commit-msg"), script)?;
+            fs::write(hooks.join("pre-push"), PRE_PUSH)?;
+            Ok(dir)
+        }
+
+        /// Uninstalls the hooks of the repository in the given directory.
+        fn uninstall(root: &Path, force: bool) -> cli::Result {
+            let context = Context {
+                repository: Repository::open(root)?,
+                workspace: Workspace::<Cargo>::read(root.join("Cargo.toml"))?,
+                config: Config::default(),
+                format: Format::Text,
+            };
+            Arguments { force }.execute(context)
+        }
+
+        #[test]
+        fn handles_managed_hooks() -> Result {
+            let dir = setup(false)?;
+            let hooks = dir.path().join(".git/hooks");
+            uninstall(dir.path(), false)?;
+            assert!(!hooks.join("commit-msg").exists());
+            assert!(!hooks.join("pre-push").exists());
+
+            // Uninstalling again must succeed, as there's nothing to remove
+            uninstall(dir.path(), false)?;
+            Ok(())
+        }
+
+        #[test]
+        fn handles_unmanaged_hooks() -> Result {
+            let dir = setup(true)?;
+            let hooks = dir.path().join(".git/hooks");
+            uninstall(dir.path(), false)?;
+            assert!(hooks.join("commit-msg").exists());
+            assert!(!hooks.join("pre-push").exists());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_force() -> Result {
+            let dir = setup(true)?;
+            let hooks = dir.path().join(".git/hooks");
+            uninstall(dir.path(), true)?;
+            assert!(!hooks.join("commit-msg").exists());
+            assert!(!hooks.join("pre-push").exists());
+            Ok(())
+        }
+    }
+}
@@ -26,10 +26,14 @@
 //! List the names of all packages in topological order.
 
 use clap::Args;
+use semver::Version;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 use ctrl_z_project::Manifest;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result};
 use crate::Context;
 
 // ----------------------------------------------------------------------------
@@ -40,6 +44,17 @@ use crate::Context;
 #[derive(Args, Debug)]
 pub struct Arguments {}
 
+/// Package.
+#[derive(Debug, Serialize)]
+struct Package<'a> {
+    /// Package name.
+    name: &'a str,
+    /// Package path, relative to the workspace root.
+    path: PathBuf,
+    /// Package version.
+    version: Option<&'a Version>,
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -51,11 +66,37 @@ where
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
         let dependents = context.workspace.dependents()?;
-        for node in &dependents {
-            let name = dependents[node].name().expect("invariant");
-            println!("{name}");
+        if context.format == Format::Text {
+            for node in &dependents {
+                let name = dependents[node].name().expect("invariant");
+                println!("{name}");
+            }
+            return Ok(());
         }
 
+        // Map package names to their paths relative to the workspace root, and
+        // collect all packages in topological order for serialization
+        let mut paths = context
+            .workspace
+            .packages()
+            .map(|(path, name)| (name, path))
+            .collect::<BTreeMap<_, _>>();
+        let packages = dependents
+            .into_iter()
+            .map(|node| {
+                let project = &dependents[node];
+                let name = project.name().expect("invariant");
+                Package {
+                    name,
+                    path: paths.remove(name).expect("invariant"),
+                    version: project.version(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Write packages to standard out
+        println!("{}", serde_json::to_string_pretty(&packages)?);
+
         // No errors occurred
         Ok(())
     }
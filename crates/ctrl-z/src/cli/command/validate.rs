@@ -33,6 +33,15 @@ use crate::cli::{Command, Result};
 use crate::Context;
 
 mod commit;
+mod commits;
+mod tags;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Prefixes of commits that are meant to be squashed during a rebase.
+const AUTOSQUASH: [&str; 3] = ["fixup! ", "squash! ", "amend! "];
 
 // ----------------------------------------------------------------------------
 // Enums
@@ -43,6 +52,10 @@ mod commit;
 pub enum Commands {
     /// Validate a commit message.
     Commit(commit::Arguments),
+    /// Validate the commit messages of a revision range.
+    Commits(commits::Arguments),
+    /// Validate that release tags are annotated.
+    Tags(tags::Arguments),
 }
 
 // ----------------------------------------------------------------------------
@@ -57,6 +70,29 @@ where
     fn execute(&self, context: Context<T>) -> Result {
         match self {
             Commands::Commit(args) => args.execute(context),
+            Commands::Commits(args) => args.execute(context),
+            Commands::Tags(args) => args.execute(context),
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the part of the given summary that must be validated.
+///
+/// Merge commits are skipped, as their summary is generated by git, and commits
+/// that are meant to be squashed carry the summary of the commit they're to be
+/// squashed into, which is validated instead.
+fn summary(summary: &str) -> Option<&str> {
+    if summary.starts_with("Merge ") {
+        return None;
+    }
+
+    // Strip autosquash prefix, if any
+    let opt = AUTOSQUASH
+        .iter()
+        .find_map(|prefix| summary.strip_prefix(prefix));
+    Some(opt.unwrap_or(summary))
+}
@@ -25,24 +25,38 @@
 
 //! Validate a commit message.
 
-use clap::error::ErrorFormatter;
 use clap::Args;
-use cliclack::{confirm, input, outro};
+use cliclack::{confirm, input};
 use std::fs;
+use std::io::{self, IsTerminal, Read};
 use std::path::PathBuf;
 
 use ctrl_z_changeset::changelog::Category;
-use ctrl_z_changeset::Change;
+use ctrl_z_changeset::Changeset;
 use ctrl_z_project::Manifest;
 
 use crate::cli::{Command, Result};
 use crate::Context;
 
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Scissors line, below which git ignores everything, e.g., the diff, which
+/// is prefixed with the comment character.
+const SCISSORS: &str = " ------------------------ >8 ------------------------";
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
 
 /// Validate a commit message.
+///
+/// This command is meant to be used as a `commit-msg` hook, which is invoked
+/// by git with the path to the commit message file. Only the summary line is
+/// validated, since the body might contain arbitrary text and trailers. When
+/// a file is given and the terminal is interactive, the author is asked for a
+/// related issue, which is appended to the commit message.
 #[derive(Args, Debug)]
 pub struct Arguments {
     /// Path to commit message file.
@@ -59,56 +73,291 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
-        let message = if let Some(ref file) = self.file {
+        let message = if let Some(file) = &self.file {
             fs::read_to_string(file)?
         } else {
-            use std::io::{self, Read};
-            let mut buf = String::new();
-            io::stdin().read_to_string(&mut buf)?;
-            buf
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer)?;
+            buffer
         };
 
-        println!("message {:?}", message);
+        // Extract the summary, which is the first line that is neither empty
+        // nor a comment - if there's none, git will abort the commit anyway
+        let comment = context.repository.comment_char()?;
+        let Some(summary) =
+            content(&message, &comment).find(|line| !line.trim().is_empty())
+        else {
+            return Ok(());
+        };
+
+        // Skip summaries that are generated by git or to be squashed
+        let Some(summary) = super::summary(summary) else {
+            return Ok(());
+        };
 
-        for line in message.lines() {
-            let change: Change = match line.parse() {
-                Ok(c) => c,
-                Err(e) => {
-                    eprintln!("Invalid commit message line: {e}");
-                    std::process::exit(1);
-                }
+        // Validate summary, and stop here if the change won't make it into the
+        // changelog, or if we can't or shouldn't ask for a related issue
+        let config = context.config.changeset();
+        let changeset = Changeset::with_config(&context.workspace, config)?;
+        let change = changeset.parse(summary)?;
+        if <Option<Category>>::from(&change).is_none()
+            || !io::stderr().is_terminal()
+            || content(&message, &comment).any(is_reference)
+        {
+            return Ok(());
+        }
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+
+        // Ask whether the commit is related to an issue, and if so, whether it
+        // resolves it, so we can reference the issue in the commit message
+        let related = confirm("Is this commit related to an issue?")
+            .initial_value(true)
+            .interact()?;
+        if related {
+            let issue: u32 = input("What's the number of the issue?")
+                .placeholder("e.g. 123")
+                .interact()?;
+            let resolves = confirm("Does the commit resolve the issue?")
+                .initial_value(false)
+                .interact()?;
+
+            // Append reference to commit message, which is picked up when the
+            // commit is added to a changeset, and write it back to the file
+            let reference = if resolves {
+                format!("Fixes #{issue}")
+            } else {
+                format!("Refs #{issue}")
             };
-            if <Option<Category>>::from(&change).is_none() {
-                continue;
-            }
+            fs::write(file, append(&message, &reference, &comment))?;
+        }
 
-            // if file, then interactively - @todo
-            if self.file.is_some() {
-                let confirm2 = confirm("Is this commit related to an issue?")
-                    .initial_value(true)
-                    .interact()?;
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns an iterator over the lines of the message that git keeps.
+///
+/// Comment lines, which start with the given comment character, are skipped,
+/// and everything below the scissors line is cut off, which is what git does
+/// when cleaning up the commit message.
+fn content<'a>(
+    message: &'a str, comment: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    message
+        .lines()
+        .take_while(move |line| !is_scissors(line, comment))
+        .filter(move |line| !line.starts_with(comment))
+}
+
+/// Returns whether the given line is the scissors line.
+fn is_scissors(line: &str, comment: &str) -> bool {
+    line.strip_prefix(comment) == Some(SCISSORS)
+}
+
+/// Returns whether the given line is an issue reference.
+fn is_reference(line: &str) -> bool {
+    ["Fixes #", "Refs #"]
+        .iter()
+        .filter_map(|prefix| line.strip_prefix(prefix))
+        .any(|issue| issue.parse::<u32>().is_ok())
+}
 
-                if confirm2 {
-                    let issue: u32 = input("What's the number of the issue?")
-                        .placeholder("e.g. 123")
-                        .interact()?;
+/// Returns whether the given line is a trailer, e.g., `Signed-off-by: ...`.
+fn is_trailer(line: &str) -> bool {
+    is_reference(line)
+        || line.split_once(": ").is_some_and(|(key, _)| {
+            !key.is_empty()
+                && key
+                    .chars()
+                    .all(|char| char.is_alphanumeric() || char == '-')
+        })
+}
 
-                    confirm("Does the commit resolve the issue?")
-                        .initial_value(false)
-                        .interact()?;
+/// Appends the given line to the message, right after the last content line.
+///
+/// Comments and the scissors line are preserved below the appended line, so
+/// git can clean up the message as usual. If the message ends with trailers,
+/// the line is added to them, or separated by an empty line otherwise.
+fn append(message: &str, line: &str, comment: &str) -> String {
+    let lines = message.lines().collect::<Vec<_>>();
+    let cut = lines
+        .iter()
+        .position(|line| is_scissors(line, comment))
+        .unwrap_or(lines.len());
+
+    // Determine the end of the content, and check if the last paragraph only
+    // consists of trailers, as we want to add the reference to them
+    let end = lines[..cut]
+        .iter()
+        .rposition(|line| !line.starts_with(comment) && !line.trim().is_empty())
+        .map_or(0, |index| index + 1);
+    let start = lines[..end]
+        .iter()
+        .rposition(|line| line.trim().is_empty())
+        .map_or(0, |index| index + 1);
+
+    // Since the summary is never a trailer, the first paragraph can't be one
+    let mut output = lines[..end].join("\n");
+    if start > 0 && lines[start..end].iter().all(|line| is_trailer(line)) {
+        output.push('\n');
+    } else {
+        output.push_str("\n\n");
+    }
 
-                    // add issue number.
-                }
+    // Append line and the remaining lines, i.e., comments
+    output.push_str(line);
+    output.push('\n');
+    for line in &lines[end..] {
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod content {
+        use crate::cli::command::validate::commit::content;
+
+        #[test]
+        fn handles_comments_and_scissors() {
+            let scissors =
+                "; ------------------------ >8 ------------------------";
+            let message =
+                format!("; comment\nfeature: add parser\n# kept\n{scissors}\n");
+            assert_eq!(
+                content(&message, ";").collect::<Vec<_>>(),
+                ["feature: add parser", "# kept"]
+            );
+        }
+    }
+
+    mod is_trailer {
+        use crate::cli::command::validate::commit::is_trailer;
+
+        #[test]
+        fn handles_trailers() {
+            for line in [
+                "Signed-off-by: Alice <alice@example.com>",
+                "Co-authored-by: Bob <bob@example.com>",
+                "BREAKING-CHANGE: use the new api",
+                "Fixes #123",
+                "Refs #123",
+            ] {
+                assert!(is_trailer(line), "{line}");
+            }
+        }
+
+        #[test]
+        fn handles_non_trailers() {
+            for line in [
+                "",
+                "Some text",
+                "Some text: with a colon",
+                "BREAKING CHANGE: use the new api",
+                ": missing key",
+                "Fixes #abc",
+            ] {
+                assert!(!is_trailer(line), "{line}");
             }
+        }
+    }
 
-            // add things to commit message. lets try that..
+    mod append {
+        use crate::cli::command::validate::commit::append;
 
-            // @todo valid commit message
+        #[test]
+        fn handles_summary_only() {
+            assert_eq!(
+                append("feature: add parser\n", "Fixes #1", "#"),
+                "feature: add parser\n\nFixes #1\n"
+            );
+        }
 
-            // @todo: how to add it to the message?
-            outro("Added ")?;
+        #[test]
+        fn handles_body_without_trailers() {
+            assert_eq!(
+                append("feature: add parser\n\nSome text\n", "Fixes #1", "#"),
+                "feature: add parser\n\nSome text\n\nFixes #1\n"
+            );
         }
 
-        Ok(())
+        #[test]
+        fn handles_body_with_trailers() {
+            assert_eq!(
+                append(
+                    "feature: add parser\n\nSome text\n\n\
+                     Signed-off-by: Alice <alice@example.com>\n",
+                    "Fixes #1",
+                    "#"
+                ),
+                "feature: add parser\n\nSome text\n\n\
+                 Signed-off-by: Alice <alice@example.com>\nFixes #1\n"
+            );
+        }
+
+        #[test]
+        fn handles_summary_with_trailer_like_text() {
+            assert_eq!(
+                append("fix: handle input\n", "Refs #1", "#"),
+                "fix: handle input\n\nRefs #1\n"
+            );
+        }
+
+        #[test]
+        fn handles_comments_and_scissors() {
+            let scissors =
+                "# ------------------------ >8 ------------------------";
+            let message = format!(
+                "feature: add parser\n\n# Please enter the commit message\n\
+                 {scissors}\ndiff --git a/b b/b\n"
+            );
+            assert_eq!(
+                append(&message, "Fixes #1", "#"),
+                format!(
+                    "feature: add parser\n\nFixes #1\n\n\
+                     # Please enter the commit message\n\
+                     {scissors}\ndiff --git a/b b/b\n"
+                )
+            );
+        }
+
+        #[test]
+        fn handles_custom_comment_char() {
+            let scissors =
+                "; ------------------------ >8 ------------------------";
+            let message = format!(
+                "feature: add parser\n\n; Please enter the commit message\n\
+                 {scissors}\nFixes: not a trailer\n"
+            );
+            assert_eq!(
+                append(&message, "Fixes #1", ";"),
+                format!(
+                    "feature: add parser\n\nFixes #1\n\n\
+                     ; Please enter the commit message\n\
+                     {scissors}\nFixes: not a trailer\n"
+                )
+            );
+        }
+
+        #[test]
+        fn handles_hash_with_custom_comment_char() {
+            assert_eq!(
+                append("feature: add parser\n\n#hashtag\n", "Fixes #1", ";"),
+                "feature: add parser\n\n#hashtag\n\nFixes #1\n"
+            );
+        }
     }
 }
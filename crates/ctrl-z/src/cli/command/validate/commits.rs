@@ -0,0 +1,321 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Validate the commit messages of a revision range.
+
+use clap::Args;
+use serde::Serialize;
+
+use ctrl_z_changeset::change;
+use ctrl_z_changeset::Changeset;
+use ctrl_z_project::Manifest;
+use ctrl_z_repository::{Id, Repository};
+
+use crate::cli::{Command, Error, Format, Result};
+use crate::Context;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Validate the commit messages of a revision range.
+///
+/// This command is meant to be used in pull request checks, and validates the
+/// summaries of all commits in the given range, e.g., `origin/master..HEAD`.
+/// Every invalid commit is reported, before exiting with a non-zero status.
+#[derive(Args, Debug)]
+pub struct Arguments {
+    /// Revision range, e.g., origin/master..HEAD.
+    range: String,
+}
+
+/// Report of invalid commits.
+#[derive(Debug, Serialize)]
+struct Report {
+    /// Number of validated commits.
+    total: usize,
+    /// Invalid commits.
+    invalid: Vec<Invalid>,
+}
+
+/// Invalid commit.
+#[derive(Debug, Serialize)]
+struct Invalid {
+    /// Commit identifier.
+    id: String,
+    /// Commit summary.
+    summary: String,
+    /// Violated rule.
+    rule: &'static str,
+    /// Error message.
+    message: String,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Arguments
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    fn execute(&self, context: Context<T>) -> Result {
+        let (start, end) = resolve(&context.repository, &self.range)?;
+        let commits = match end {
+            Some(end) => context.repository.commits(start..end)?,
+            None => context.repository.commits(start..)?,
+        };
+
+        // Validate the summary of each commit, skipping those generated by git
+        // or meant to be squashed, and collect all invalid commits
+        let config = context.config.changeset();
+        let changeset = Changeset::with_config(&context.workspace, config)?;
+        let mut report = Report { total: 0, invalid: Vec::new() };
+        for commit in commits {
+            let commit = commit?;
+            let Some(summary) = super::summary(commit.summary()) else {
+                continue;
+            };
+
+            // Validate summary and record violation, if any
+            report.total += 1;
+            if let Err(err) = changeset.parse(summary) {
+                report.invalid.push(Invalid {
+                    id: commit.id().short(),
+                    summary: commit.summary().to_string(),
+                    rule: rule(&err),
+                    message: err.to_string(),
+                });
+            }
+        }
+
+        // Write report to standard out
+        match context.format {
+            Format::Json => {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            }
+            Format::Text => print(&report),
+        }
+
+        // Exit with a non-zero status, if there are invalid commits
+        if report.invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Commits(report.invalid.len(), report.total))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Resolves the given revision range into the start and end of the range.
+///
+/// Revision ranges follow git's notation, so `a..b` denotes all commits that
+/// are reachable from `b`, but not from `a`, where an omitted side defaults to
+/// `HEAD`. A single revision denotes all commits reachable from it.
+fn resolve(repository: &Repository, range: &str) -> Result<(Id, Option<Id>)> {
+    let find = |spec: &str| {
+        let spec = if spec.is_empty() { "HEAD" } else { spec };
+        repository.find(spec).map(|commit| commit.id())
+    };
+
+    // Note that the start of the range is the newest commit, since commits are
+    // iterated in reverse chronological order
+    match range.split_once("..") {
+        Some((from, to)) => Ok((find(to)?, Some(find(from)?))),
+        None => Ok((find(range)?, None)),
+    }
+}
+
+/// Returns the name of the rule that the given error is a violation of.
+fn rule(err: &change::Error) -> &'static str {
+    match err {
+        change::Error::Format => "format",
+        change::Error::Kind => "kind",
+        change::Error::Scope => "scope",
+        change::Error::Whitespace => "whitespace",
+        change::Error::Casing => "casing",
+        change::Error::Punctuation => "punctuation",
+        change::Error::Reference => "reference",
+    }
+}
+
+/// Prints the given report as a table.
+fn print(report: &Report) {
+    if report.invalid.is_empty() {
+        println!("All {} commits are valid", report.total);
+        return;
+    }
+
+    // Determine column widths, so all columns are aligned
+    let rule = report.invalid.iter().map(|invalid| invalid.rule.len());
+    let message = report.invalid.iter().map(|invalid| invalid.message.len());
+    let rule = rule.chain(["Rule".len()]).max().unwrap_or_default();
+    let message = message.chain(["Error".len()]).max().unwrap_or_default();
+
+    // Print header and rows
+    println!(
+        "{:<7}  {:<rule$}  {:<message$}  Summary",
+        "Commit", "Rule", "Error"
+    );
+    for invalid in &report.invalid {
+        println!(
+            "{:<7}  {:<rule$}  {:<message$}  {}",
+            invalid.id, invalid.rule, invalid.message, invalid.summary
+        );
+    }
+
+    // Print summary
+    println!();
+    println!(
+        "{} of {} commits are invalid",
+        report.invalid.len(),
+        report.total
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod resolve {
+        use std::error::Error;
+        use std::path::Path;
+        use std::process::Command;
+        use tempfile::TempDir;
+
+        use ctrl_z_repository::Repository;
+
+        use crate::cli::command::validate::commits::resolve;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Runs git with the given arguments in the given directory.
+        fn git(root: &Path, args: &[&str]) -> Result<String> {
+            let output = Command::new("git")
+                .current_dir(root)
+                .args(["-c", "user.name=Alice", "-c", "user.email=a@b.c"])
+                .args(["-c", "commit.gpgsign=false"])
+                .args(args)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8(output.stdout)?.trim().to_string())
+        }
+
+        /// Creates a repository with two commits, and returns the temporary
+        /// directory, as well as the repository.
+        fn setup() -> Result<(TempDir, Repository)> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            git(root, &["init", "--quiet"])?;
+            for message in ["feature: add parser", "fix: handle empty input"] {
+                git(
+                    root,
+                    &["commit", "--quiet", "--allow-empty", "-m", message],
+                )?;
+            }
+            let repository = Repository::open(root)?;
+            Ok((dir, repository))
+        }
+
+        #[test]
+        fn handles_range() -> Result {
+            let (dir, repository) = setup()?;
+            let head = git(dir.path(), &["rev-parse", "HEAD"])?;
+            let prev = git(dir.path(), &["rev-parse", "HEAD~1"])?;
+            let (start, end) = resolve(&repository, "HEAD~1..HEAD")?;
+            assert_eq!(start.to_string(), head);
+            assert_eq!(end.map(|id| id.to_string()), Some(prev));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_range_with_omitted_side() -> Result {
+            let (dir, repository) = setup()?;
+            let head = git(dir.path(), &["rev-parse", "HEAD"])?;
+            let (start, _) = resolve(&repository, "HEAD~1..")?;
+            assert_eq!(start.to_string(), head);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_single_revision() -> Result {
+            let (dir, repository) = setup()?;
+            let prev = git(dir.path(), &["rev-parse", "HEAD~1"])?;
+            let (start, end) = resolve(&repository, "HEAD~1")?;
+            assert_eq!(start.to_string(), prev);
+            assert!(end.is_none());
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_revision() -> Result {
+            let (_dir, repository) = setup()?;
+            for range in ["unknown", "unknown..HEAD", "HEAD..unknown"] {
+                assert!(resolve(&repository, range).is_err());
+            }
+            Ok(())
+        }
+    }
+
+    mod report {
+        use serde_json::json;
+
+        use crate::cli::command::validate::commits::{Invalid, Report};
+
+        #[test]
+        fn handles_json() -> serde_json::Result<()> {
+            let report = Report {
+                total: 2,
+                invalid: vec![Invalid {
+                    id: String::from("1a2b3c4"),
+                    summary: String::from("feat: add parser"),
+                    rule: "kind",
+                    message: String::from("invalid kind"),
+                }],
+            };
+            assert_eq!(
+                serde_json::to_value(&report)?,
+                json!({
+                    "total": 2,
+                    "invalid": [{
+                        "id": "1a2b3c4",
+                        "summary": "feat: add parser",
+                        "rule": "kind",
+                        "message": "invalid kind"
+                    }]
+                })
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Validate that release tags are annotated.
+
+use clap::Args;
+use serde::Serialize;
+use std::result;
+
+use ctrl_z_project::Manifest;
+
+use crate::cli::command::version;
+use crate::cli::{Command, Error, Format, Result, Versioning};
+use crate::Context;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Validate that release tags are annotated.
+///
+/// Lightweight tags carry neither a message, nor a tagger, nor a signature, so
+/// releases tagged with them can't be verified. This command reports all such
+/// release tags, before exiting with a non-zero status.
+#[derive(Args, Debug)]
+pub struct Arguments {
+    /// Only validate tags of the package with the given name.
+    #[arg(short, long)]
+    package: Option<String>,
+}
+
+/// Report of lightweight release tags.
+#[derive(Debug, Serialize)]
+struct Report {
+    /// Number of validated tags.
+    total: usize,
+    /// Lightweight tags.
+    lightweight: Vec<String>,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Arguments
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    fn execute(&self, context: Context<T>) -> Result {
+        // With independent versioning, each package has its own series of
+        // tags, so unless a package is given, we validate those of all packages
+        let sets = match (&self.package, context.config.repository.versioning) {
+            (None, Versioning::Independent) => context
+                .workspace
+                .packages()
+                .map(|(_, name)| context.repository.package_versions(name))
+                .collect::<result::Result<Vec<_>, _>>()?,
+            (package, _) => {
+                vec![version::versions(&context, package.as_deref())?]
+            }
+        };
+
+        // Validate that each release tag is annotated
+        let mut report = Report {
+            total: 0,
+            lightweight: Vec::new(),
+        };
+        for versions in &sets {
+            for (version, _) in versions {
+                report.total += 1;
+                if !versions.is_annotated(version) {
+                    report.lightweight.push(versions.tag(version));
+                }
+            }
+        }
+
+        // Write report to standard out
+        match context.format {
+            Format::Json => {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            }
+            Format::Text => print(&report),
+        }
+
+        // Exit with a non-zero status, if there are lightweight tags
+        if report.lightweight.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Tags(report.lightweight.len(), report.total))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Prints the given report as a list.
+fn print(report: &Report) {
+    if report.lightweight.is_empty() {
+        println!("All {} release tags are annotated", report.total);
+        return;
+    }
+
+    // Print lightweight tags
+    for tag in &report.lightweight {
+        println!("{tag}");
+    }
+
+    // Print summary
+    println!();
+    println!(
+        "{} of {} release tags are not annotated",
+        report.lightweight.len(),
+        report.total
+    );
+}
@@ -27,15 +27,27 @@
 
 use clap::Subcommand;
 
+use ctrl_z_changeset::Changeset;
+use ctrl_z_project::version::Increment;
+use ctrl_z_project::workspace::writer::{Writable, Writer};
 use ctrl_z_project::Manifest;
+use ctrl_z_repository::Versions;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Error, Result, Versioning};
 use crate::Context;
 
 mod changed;
 mod changelog;
 mod create;
 mod list;
+mod undo;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Subject prefix of release commits.
+const RELEASE: &str = "chore: release ";
 
 // ----------------------------------------------------------------------------
 // Enums
@@ -48,10 +60,12 @@ pub enum Commands {
     Create(create::Arguments),
     /// List versions in reverse chronological order.
     List(list::Arguments),
-    /// Generate the changelog of a version in Markdown format.
+    /// Generate the changelog of a version in the given format.
     Changelog(changelog::Arguments),
     /// List the names of changed packages in topological order.
     Changed(changed::Arguments),
+    /// Undo the latest release, unless it was already pushed.
+    Undo(undo::Arguments),
 }
 
 // ----------------------------------------------------------------------------
@@ -61,6 +75,7 @@ pub enum Commands {
 impl<T> Command<T> for Commands
 where
     T: Manifest,
+    Writer<T>: Writable,
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
@@ -69,6 +84,74 @@ where
             Commands::Changelog(args) => args.execute(context),
             Commands::Create(args) => args.execute(context),
             Commands::List(args) => args.execute(context),
+            Commands::Undo(args) => args.execute(context),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the version set of the given package, or of the workspace.
+///
+/// With lockstep versioning, all packages share the version set of the
+/// workspace, whereas with independent versioning, each package has its own
+/// series of tags, which is why the package must be given.
+pub(super) fn versions<'a, T>(
+    context: &'a Context<T>, package: Option<&str>,
+) -> Result<Versions<'a>>
+where
+    T: Manifest,
+{
+    if let Some(name) = package {
+        if context.workspace.get(name).is_none() {
+            return Err(Error::Package(name.to_string()));
         }
     }
+
+    // Resolve version set depending on versioning mode
+    match (context.config.repository.versioning, package) {
+        (Versioning::Lockstep, _) => Ok(context.repository.versions()?),
+        (Versioning::Independent, Some(name)) => {
+            Ok(context.repository.package_versions(name)?)
+        }
+        (Versioning::Independent, None) => Err(Error::Versioning),
+    }
+}
+
+/// Returns the version increments of all packages from unreleased changes.
+///
+/// With lockstep versioning, unreleased changes are all changes since the
+/// latest version of the workspace, whereas with independent versioning, they
+/// are determined for each package since its own latest version.
+fn increments<T>(context: &Context<T>) -> Result<Vec<Option<Increment>>>
+where
+    T: Manifest,
+{
+    let config = context.config.changeset();
+    if context.config.repository.versioning == Versioning::Lockstep {
+        let versions = context.repository.versions()?;
+        let mut changeset = Changeset::with_config(&context.workspace, config)?;
+        changeset.extend(versions.unreleased()?.flatten())?;
+        return Ok(changeset.increments().to_vec());
+    }
+
+    // Create a changeset for each package from the commits since its latest
+    // version, and only take the version increment of the package itself
+    let mut increments = Vec::new();
+    for (_, name) in context.workspace.packages() {
+        let versions = context.repository.package_versions(&name)?;
+        let mut changeset =
+            Changeset::with_config(&context.workspace, config.clone())?;
+        changeset.extend(versions.unreleased()?.flatten())?;
+
+        // Scope indices are identical across changesets of the same workspace
+        let index = changeset.scopes().find(&name).expect("invariant");
+        increments.resize(changeset.increments().len(), None);
+        increments[index] = changeset.increments()[index];
+    }
+
+    // Return version increments
+    Ok(increments)
 }
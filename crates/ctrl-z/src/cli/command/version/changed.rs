@@ -27,12 +27,15 @@
 
 use clap::Args;
 use semver::Version;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 use ctrl_z_changeset::Changeset;
-use ctrl_z_project::version::VersionExt;
+use ctrl_z_project::version::{Increment, VersionExt};
 use ctrl_z_project::Manifest;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result};
 use crate::Context;
 
 // ----------------------------------------------------------------------------
@@ -45,6 +48,22 @@ pub struct Arguments {
     /// Version in x.y.z format
     #[arg(value_parser = Version::from_str_with_prefix)]
     version: Option<Version>,
+    /// Only consider the package with the given name.
+    #[arg(short, long)]
+    package: Option<String>,
+}
+
+/// Changed package.
+#[derive(Debug, Serialize)]
+struct Package<'a> {
+    /// Package name.
+    name: &'a str,
+    /// Package path, relative to the workspace root.
+    path: PathBuf,
+    /// Package version.
+    version: Option<&'a Version>,
+    /// Version increment.
+    increment: Increment,
 }
 
 // ----------------------------------------------------------------------------
@@ -57,27 +76,62 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
-        // Resolve versions and create changeset, then determine all commits
-        // that are either part of the given version or yet unreleased
-        let versions = context.repository.versions()?;
-        let mut changeset = Changeset::new(&context.workspace)?;
-        if let Some(version) = &self.version {
-            changeset.extend(versions.commits(version)?.flatten())?;
+        // Determine version increments from all commits that are either part
+        // of the given version or yet unreleased - without a version or package
+        // the increments of unreleased changes are computed for all packages,
+        // which takes the versioning mode into account
+        let increments = if self.version.is_none() && self.package.is_none() {
+            super::increments(&context)?
         } else {
-            changeset.extend(versions.unreleased()?.flatten())?;
-        }
+            let versions = super::versions(&context, self.package.as_deref())?;
+            let mut changeset = Changeset::with_config(
+                &context.workspace,
+                context.config.changeset(),
+            )?;
+            if let Some(version) = &self.version {
+                changeset.extend(versions.commits(version)?.flatten())?;
+            } else {
+                changeset.extend(versions.unreleased()?.flatten())?;
+            }
+            changeset.increments().to_vec()
+        };
 
-        // Obtain version increments, which denote which packages have changed,
-        // and traverse dependents to list changed packages in topological order
-        let increments = changeset.increments();
+        // Version increments denote which packages have changed, so traverse
+        // dependents to list changed packages in topological order
         let dependents = context.workspace.dependents()?;
+        let mut paths = context
+            .workspace
+            .packages()
+            .map(|(path, name)| (name, path))
+            .collect::<BTreeMap<_, _>>();
+
+        // Collect changed packages, or write their names to standard out right
+        // away, if no structured output was requested
+        let mut packages = Vec::new();
         for node in &dependents {
-            if increments[node].is_some() {
-                let name = dependents[node].name().expect("invariant");
-                println!("{name}");
+            if let Some(increment) = increments[node] {
+                let project = &dependents[node];
+                let name = project.name().expect("invariant");
+                if self.package.as_deref().is_some_and(|n| n != name) {
+                    continue;
+                }
+                match context.format {
+                    Format::Json => packages.push(Package {
+                        name,
+                        path: paths.remove(name).expect("invariant"),
+                        version: project.version(),
+                        increment,
+                    }),
+                    Format::Text => println!("{name}"),
+                }
             }
         }
 
+        // Write changed packages to standard out, if requested
+        if context.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&packages)?);
+        }
+
         // No errors occurred
         Ok(())
     }
@@ -23,24 +23,37 @@
 
 // ----------------------------------------------------------------------------
 
-//! Generate the changelog of a version in Markdown format.
+//! Generate the changelog of a version in the given format.
 
-use clap::Args;
+use clap::{Args, ValueEnum};
+use cliclack::log::success;
 use semver::Version;
+use std::fs;
+use std::path::PathBuf;
 
+use ctrl_z_changeset::changelog::renderer::{
+    Html, Json, KeepAChangelog, Markdown, Renderer, Template,
+};
+use ctrl_z_changeset::changelog::Changelog;
 use ctrl_z_changeset::Changeset;
 use ctrl_z_project::version::VersionExt;
 use ctrl_z_project::Manifest;
+use ctrl_z_repository::Versions;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result};
 use crate::Context;
 
+mod file;
+
+use file::{File, FILE};
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
 
-/// Generate the changelog of a version in Markdown format.
+/// Generate the changelog of a version in the given format.
 #[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Arguments {
     /// Version in x.y.z format
     #[arg(value_parser = Version::from_str_with_prefix)]
@@ -48,6 +61,167 @@ pub struct Arguments {
     /// Include version summary.
     #[arg(short, long)]
     summary: bool,
+    /// Only include changes affecting the package with the given name.
+    #[arg(short, long)]
+    package: Option<String>,
+    /// Insert the changelog of the version into the changelog file.
+    #[arg(short, long, requires = "version")]
+    write: bool,
+    /// Regenerate the changelog file from all versions.
+    #[arg(short, long, conflicts_with_all = ["version", "write", "summary"])]
+    all: bool,
+    /// Mark contributors whose first contribution is part of the changelog.
+    #[arg(short, long)]
+    new_contributors: bool,
+    /// Render the changelog with the template at the given path.
+    #[arg(short, long, value_name = "PATH", conflicts_with_all = ["write", "all"])]
+    template: Option<PathBuf>,
+    /// Render the changelog with the given renderer.
+    ///
+    /// Defaults to Markdown, or JSON if the output format is JSON.
+    #[arg(short, long, value_enum)]
+    #[arg(conflicts_with_all = ["write", "all", "template"])]
+    renderer: Option<Kind>,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Changelog renderer kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Kind {
+    /// Markdown.
+    Markdown,
+    /// Keep a Changelog Markdown.
+    KeepAChangelog,
+    /// HTML.
+    Html,
+    /// JSON.
+    Json,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Arguments {
+    /// Creates a changeset from the commits of the given version.
+    ///
+    /// If no version is given, the changeset is created from all unreleased
+    /// commits. If a package is given, the changeset is narrowed down to the
+    /// revisions affecting the package, so the changelog only includes the
+    /// changes of the package.
+    fn changeset<'a, T>(
+        &self, context: &Context<T>, versions: &'a Versions,
+        version: Option<&Version>,
+    ) -> Result<Changeset<'a>>
+    where
+        T: Manifest,
+    {
+        let mut changeset = Changeset::with_config(
+            &context.workspace,
+            context.config.changeset(),
+        )?;
+        if let Some(version) = version {
+            changeset.extend(versions.commits(version)?.flatten())?;
+        } else {
+            changeset.extend(versions.unreleased()?.flatten())?;
+        }
+
+        // Narrow the changeset down to the revisions affecting the package
+        if let Some(name) = &self.package {
+            let index = changeset.scopes().find(name).expect("invariant");
+            changeset.retain(|revision| revision.scopes().contains(&index));
+        }
+
+        // Return changeset
+        Ok(changeset)
+    }
+
+    /// Creates a changelog from the given changeset.
+    ///
+    /// If a version is given, the changelog includes the version, its release
+    /// date and the range of tags to compare, which is rendered as a link to
+    /// the full diff, if the forge is known. Commits and issues link to the
+    /// forge, and first time contributors are marked, if requested.
+    fn changelog<'a, T>(
+        &self, context: &'a Context<T>, versions: &Versions,
+        changeset: &'a Changeset, version: Option<&Version>,
+    ) -> Result<Changelog<'a>>
+    where
+        T: Manifest,
+    {
+        let mut changelog = changeset.to_changelog();
+        if let Some(forge) = context.repository.forge() {
+            changelog = changelog.with_forge(forge);
+        }
+
+        // Add version, release date, and range of tags to compare
+        if let Some(version) = version {
+            let date = versions.date(version).expect("invariant");
+            changelog = changelog.with_version(version.clone()).with_date(date);
+            if let Some(prev) = versions.previous(version)? {
+                let range = (versions.tag(prev), versions.tag(version));
+                changelog = changelog.with_range(range.0, range.1);
+            }
+        }
+
+        // Mark first time contributors, which requires a walk over all commits
+        // before the version, so it's only done when requested
+        if self.new_contributors {
+            let known = versions.contributors(version)?;
+            changelog = changelog.with_known_contributors(known);
+        }
+
+        // Return changelog
+        Ok(changelog)
+    }
+
+    /// Writes the changelog of the version into the changelog file.
+    ///
+    /// The changelog of the version is inserted into the changelog file, in
+    /// the order of versions, or, if all versions should be written, the
+    /// generated part of the changelog file is regenerated from scratch, e.g.,
+    /// for backfilling.
+    fn write<T>(&self, context: &Context<T>, versions: &Versions) -> Result
+    where
+        T: Manifest,
+    {
+        let path = match &self.package {
+            Some(name) => {
+                let project = context.workspace.get(name).expect("invariant");
+                project.path().with_file_name(FILE)
+            }
+            None => context.workspace.path().join(FILE),
+        };
+
+        // Read changelog file, and either regenerate all sections, with the
+        // latest version first, or insert the section of the given version
+        let mut file = File::read(&path)?;
+        if self.all {
+            file.clear();
+            for (version, _) in versions {
+                let version = Some(version);
+                let changeset = self.changeset(context, versions, version)?;
+                let changelog =
+                    self.changelog(context, versions, &changeset, version)?;
+                file.push(changelog.render(&Markdown)?);
+            }
+        } else {
+            let version = self.version.as_ref();
+            let changeset = self.changeset(context, versions, version)?;
+            let changelog =
+                self.changelog(context, versions, &changeset, version)?;
+            let version = version.expect("invariant");
+            file.insert(version, changelog.render(&Markdown)?);
+        }
+
+        // Write changelog file
+        file.write()?;
+        success(format!("Updated {}", file.path().display()))?;
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -60,35 +234,51 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
-        // Resolve versions and create changeset, then determine all commits
-        // that are either part of the given version or yet unreleased
-        let versions = context.repository.versions()?;
-        let mut changeset = Changeset::new(&context.workspace)?;
-        if let Some(version) = &self.version {
-            changeset.extend(versions.commits(version)?.flatten())?;
-        } else {
-            changeset.extend(versions.unreleased()?.flatten())?;
+        // Resolve versions, and write the changelog file, if requested, which
+        // is located next to the manifest of the package or workspace
+        let versions = super::versions(&context, self.package.as_deref())?;
+        if self.write || self.all {
+            return self.write(&context, &versions);
         }
 
-        // Create queue for writing to standard out - since we are particularly
-        // careful about line feeds, we collect everything before writing
-        let mut queue = Vec::new();
-        if self.summary {
-            queue.push(changeset.summary()?);
+        // Create changeset from all commits that are either part of the given
+        // version or yet unreleased
+        let changeset =
+            self.changeset(&context, &versions, self.version.as_ref())?;
+
+        // Create changelog, including the version and its summary, if given
+        let version = self.version.as_ref();
+        let mut changelog =
+            self.changelog(&context, &versions, &changeset, version)?;
+        let summary = self.summary.then(|| changeset.summary()).transpose()?;
+        if let Some(summary) = summary {
+            changelog = changelog.with_summary(summary);
         }
 
         // Only write to standard out if the changelog is not empty, which can
         // happen despite changes being present - this happens when changes do
         // not touch published artifacts, as they solely improve on formatting,
-        // documentation, or the build setup.
-        let changelog = changeset.to_changelog();
-        if !changelog.is_empty() {
-            let value = changelog.to_string();
-            queue.push(&value);
-
-            // Got something to say
-            if !queue.is_empty() {
-                println!("{}", queue.join("\n\n"));
+        // documentation, or the build setup. Structured output is always
+        // written, so consumers can rely on it. Templates and renderers take
+        // precedence over the output format, as they are more specific.
+        if let Some(path) = &self.template {
+            let template = Template::new(fs::read_to_string(path)?)?;
+            if !changelog.is_empty() {
+                println!("{}", template.render(&changelog)?);
+            }
+        } else {
+            let kind = self.renderer.unwrap_or(match context.format {
+                Format::Text => Kind::Markdown,
+                Format::Json => Kind::Json,
+            });
+            let renderer: &dyn Renderer = match kind {
+                Kind::Markdown => &Markdown,
+                Kind::KeepAChangelog => &KeepAChangelog,
+                Kind::Html => &Html,
+                Kind::Json => &Json,
+            };
+            if kind == Kind::Json || !changelog.is_empty() {
+                println!("{}", changelog.render(renderer)?);
             }
         }
 
@@ -0,0 +1,437 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Changelog file.
+
+use semver::Version;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Result;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Changelog file name.
+pub const FILE: &str = "CHANGELOG.md";
+
+/// Changelog title.
+const TITLE: &str = "# Changelog";
+
+/// Marker separating generated from hand-written content.
+const MARKER: &str = "<!-- ctrl-z: hand-written content below is preserved -->";
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Changelog file.
+///
+/// The changelog file consists of a generated part, which contains a section
+/// for each version, with the latest version first, and a hand-written part,
+/// which follows the marker and is always preserved. Files without a marker
+/// are considered hand-written entirely, so their content is moved below the
+/// marker when the file is first written, except for the title.
+#[derive(Debug)]
+pub struct File {
+    /// File path.
+    path: PathBuf,
+    /// Generated sections, each starting with a heading.
+    sections: Vec<String>,
+    /// Hand-written content.
+    rest: String,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl File {
+    /// Attempts to read the changelog file at the given path.
+    ///
+    /// If the file doesn't exist, an empty changelog file is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][] if the file can't be read.
+    ///
+    /// [`Error::Io`]: crate::cli::Error::Io
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        // Split content at the marker, and split the generated part into its
+        // sections, each of which starts with a second-level heading
+        let mut sections = Vec::new();
+        let rest = if let Some((generated, rest)) = content.split_once(MARKER) {
+            for line in generated.lines() {
+                if line.starts_with("## ") {
+                    sections.push(String::new());
+                }
+                if let Some(section) = sections.last_mut() {
+                    section.push_str(line);
+                    section.push('\n');
+                }
+            }
+            rest.trim_start_matches('\n')
+        } else {
+            let content = content.trim_start();
+            let content = content.strip_prefix(TITLE).unwrap_or(content);
+            content.trim_start_matches('\n')
+        };
+
+        // Create and return changelog file
+        Ok(Self {
+            path: path.to_path_buf(),
+            sections: sections
+                .into_iter()
+                .map(|section| section.trim_end().to_string())
+                .collect(),
+            rest: rest.to_string(),
+        })
+    }
+
+    /// Inserts the section of a version, ordered by version.
+    ///
+    /// Sections are ordered by the versions in their headings, with the latest
+    /// version first, so the section is usually inserted at the top, but might
+    /// also be inserted further down, e.g., when backporting a fix. Sections
+    /// with headings that don't contain a version are skipped. If the file
+    /// already contains a section for the version, it's replaced in place, so
+    /// writing the same version twice is idempotent.
+    pub fn insert(&mut self, version: &Version, section: String) {
+        let mut index = self.sections.len();
+        for (i, existing) in self.sections.iter_mut().enumerate() {
+            let current = heading(existing)
+                .and_then(|value| value.parse::<Version>().ok());
+            match current {
+                Some(current) if current == *version => {
+                    *existing = section;
+                    return;
+                }
+                Some(current) if current < *version => {
+                    index = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        // Insert section before the first section of an older version
+        self.sections.insert(index, section);
+    }
+
+    /// Appends the section of a version at the bottom.
+    pub fn push(&mut self, section: String) {
+        self.sections.push(section);
+    }
+
+    /// Removes all generated sections.
+    pub fn clear(&mut self) {
+        self.sections.clear();
+    }
+
+    /// Attempts to write the changelog file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][] if the file can't be written.
+    ///
+    /// [`Error::Io`]: crate::cli::Error::Io
+    pub fn write(&self) -> Result {
+        fs::write(&self.path, self.to_string())?;
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl File {
+    /// Returns a reference to the file path.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for File {
+    /// Formats the changelog file for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(TITLE)?;
+        f.write_char('\n')?;
+
+        // Write all sections, separated by blank lines
+        for section in &self.sections {
+            f.write_char('\n')?;
+            f.write_str(section)?;
+            f.write_char('\n')?;
+        }
+
+        // Write marker and hand-written content
+        f.write_char('\n')?;
+        f.write_str(MARKER)?;
+        f.write_char('\n')?;
+        if !self.rest.is_empty() {
+            f.write_char('\n')?;
+            f.write_str(&self.rest)?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the version from the heading of the given section, which may be
+/// followed by the release date, e.g., `## 1.2.0 – 2026-10-17`.
+fn heading(section: &str) -> Option<&str> {
+    let line = section.lines().next()?;
+    line.strip_prefix("## ")?.split_whitespace().next()
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod read {
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::cli::command::version::changelog::file::{File, MARKER};
+        use crate::cli::Result;
+
+        #[test]
+        fn handles_missing_file() -> Result {
+            let dir = TempDir::new()?;
+            let file = File::read(&dir.path().join("CHANGELOG.md"))?;
+            assert!(file.sections.is_empty());
+            assert_eq!(file.rest, "");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_marker() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("CHANGELOG.md");
+            fs::write(
+                &path,
+                format!(
+                    "# Changelog\n\n## 1.1.0 – 2026-10-17\n\n- b\n\n\
+                     ## 1.0.0 – 2026-10-01\n\n- a\n\n{MARKER}\n\n## 0.1.0\n"
+                ),
+            )?;
+            let file = File::read(&path)?;
+            assert_eq!(
+                file.sections,
+                [
+                    "## 1.1.0 – 2026-10-17\n\n- b",
+                    "## 1.0.0 – 2026-10-01\n\n- a"
+                ]
+            );
+            assert_eq!(file.rest, "## 0.1.0\n");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_no_marker() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("CHANGELOG.md");
+            fs::write(&path, "## 0.1.0\n\n- Initial release\n")?;
+            let file = File::read(&path)?;
+            assert!(file.sections.is_empty());
+            assert_eq!(file.rest, "## 0.1.0\n\n- Initial release\n");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_no_marker_with_title() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("CHANGELOG.md");
+            fs::write(&path, "\n# Changelog\n\n## 0.1.0\n")?;
+            let file = File::read(&path)?;
+            assert!(file.sections.is_empty());
+            assert_eq!(file.rest, "## 0.1.0\n");
+            Ok(())
+        }
+    }
+
+    mod insert {
+        use semver::Version;
+        use std::path::PathBuf;
+
+        use crate::cli::command::version::changelog::file::File;
+
+        /// Creates a changelog file with the given sections.
+        fn file(sections: &[&str]) -> File {
+            File {
+                path: PathBuf::from("CHANGELOG.md"),
+                sections: sections.iter().map(ToString::to_string).collect(),
+                rest: String::new(),
+            }
+        }
+
+        #[test]
+        fn handles_latest_version() {
+            let mut file = file(&["## 1.0.0", "## 0.1.0"]);
+            file.insert(&Version::new(1, 1, 0), String::from("## 1.1.0"));
+            assert_eq!(file.sections, ["## 1.1.0", "## 1.0.0", "## 0.1.0"]);
+        }
+
+        #[test]
+        fn handles_backported_version() {
+            let mut file = file(&["## 1.1.0 – 2026-10-17", "## 1.0.0"]);
+            file.insert(&Version::new(1, 0, 1), String::from("## 1.0.1"));
+            assert_eq!(
+                file.sections,
+                ["## 1.1.0 – 2026-10-17", "## 1.0.1", "## 1.0.0"]
+            );
+        }
+
+        #[test]
+        fn handles_oldest_version() {
+            let mut file = file(&["## 1.0.0"]);
+            file.insert(&Version::new(0, 1, 0), String::from("## 0.1.0"));
+            assert_eq!(file.sections, ["## 1.0.0", "## 0.1.0"]);
+        }
+
+        #[test]
+        fn handles_pre_release_version() {
+            let mut file = file(&["## 1.0.0", "## 1.0.0-rc.1"]);
+            let version = Version::parse("1.0.0-rc.2").expect("invariant");
+            file.insert(&version, String::from("## 1.0.0-rc.2"));
+            assert_eq!(
+                file.sections,
+                ["## 1.0.0", "## 1.0.0-rc.2", "## 1.0.0-rc.1"]
+            );
+        }
+
+        #[test]
+        fn handles_existing_version() {
+            let mut file = file(&["## 1.1.0\n\n- a", "## 1.0.0"]);
+            let section = String::from("## 1.1.0\n\n- b");
+            file.insert(&Version::new(1, 1, 0), section.clone());
+            file.insert(&Version::new(1, 1, 0), section);
+            assert_eq!(file.sections, ["## 1.1.0\n\n- b", "## 1.0.0"]);
+        }
+
+        #[test]
+        fn handles_sections_without_version() {
+            let mut file = file(&["## Unreleased", "## 1.0.0"]);
+            file.insert(&Version::new(1, 1, 0), String::from("## 1.1.0"));
+            assert_eq!(
+                file.sections,
+                ["## Unreleased", "## 1.1.0", "## 1.0.0"]
+            );
+        }
+    }
+
+    mod fmt {
+        use semver::Version;
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::cli::command::version::changelog::file::{File, MARKER};
+        use crate::cli::Result;
+
+        #[test]
+        fn handles_empty_file() -> Result {
+            let dir = TempDir::new()?;
+            let file = File::read(&dir.path().join("CHANGELOG.md"))?;
+            assert_eq!(file.to_string(), format!("# Changelog\n\n{MARKER}\n"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_round_trip_with_marker() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("CHANGELOG.md");
+            let content = format!(
+                "# Changelog\n\n## 1.0.0 – 2026-10-17\n\n- a\n\n\
+                 {MARKER}\n\n## 0.1.0\n"
+            );
+            fs::write(&path, &content)?;
+            assert_eq!(File::read(&path)?.to_string(), content);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_round_trip_without_marker() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("CHANGELOG.md");
+            fs::write(&path, "# Changelog\n\n## 0.1.0\n")?;
+
+            // Hand-written content is moved below the marker, once
+            let file = File::read(&path)?;
+            let content = format!("# Changelog\n\n{MARKER}\n\n## 0.1.0\n");
+            assert_eq!(file.to_string(), content);
+            file.write()?;
+            assert_eq!(File::read(&path)?.to_string(), content);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_idempotent_insert() -> Result {
+            let dir = TempDir::new()?;
+            let path = dir.path().join("CHANGELOG.md");
+            fs::write(&path, "# Changelog\n\n## 0.1.0\n")?;
+
+            // Inserting the same version twice yields the same content
+            let version = Version::new(1, 0, 0);
+            let section = String::from("## 1.0.0 – 2026-10-17\n\n- a");
+            let mut file = File::read(&path)?;
+            file.insert(&version, section.clone());
+            file.write()?;
+            let content = fs::read_to_string(&path)?;
+            let mut file = File::read(&path)?;
+            file.insert(&version, section);
+            assert_eq!(file.to_string(), content);
+            assert_eq!(
+                content,
+                format!(
+                    "# Changelog\n\n## 1.0.0 – 2026-10-17\n\n- a\n\n\
+                     {MARKER}\n\n## 0.1.0\n"
+                )
+            );
+            Ok(())
+        }
+    }
+}
@@ -29,26 +29,148 @@ use clap::Args;
 use cliclack::log::remark;
 use cliclack::{intro, outro, select};
 use console::style;
-use std::io::Write;
+use globset::{Glob, GlobSetBuilder};
+use semver::{BuildMetadata, Prerelease, Version};
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process, result};
 use tempfile::NamedTempFile;
 
 use ctrl_z_changeset::Changeset;
-use ctrl_z_project::version::VersionExt;
+use ctrl_z_project as project;
+use ctrl_z_project::version::{Increment, VersionExt};
+use ctrl_z_project::workspace::dependents::Dependents;
+use ctrl_z_project::workspace::writer::{Backup, Writable, Writer};
 use ctrl_z_project::Manifest;
+use ctrl_z_repository::{Repository, Versions};
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Error, Format, Result, Versioning};
 use crate::Context;
 
+use super::RELEASE;
+
+mod plan;
+
+use plan::{File, Package, Plan, Reason};
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
 
 /// Create a new version and updates all packages.
 #[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Arguments {
     /// Use visual editor for release notes.
     #[arg(short, long)]
     visual: bool,
+    /// Select the highest suggested version increments without prompting.
+    ///
+    /// Unless release notes are read from a file, the release note template is
+    /// used as is, so the editor is never opened.
+    #[arg(short, long)]
+    yes: bool,
+    /// Version increment for a package, e.g., `ctrl-z=minor`.
+    #[arg(short, long, value_name = "PACKAGE=INCREMENT")]
+    #[arg(value_parser = parse_bump)]
+    bump: Vec<(String, Increment)>,
+    /// Read release notes from file instead of opening an editor.
+    #[arg(short, long, value_name = "FILE")]
+    message_file: Option<PathBuf>,
+    /// Print the release plan without changing anything.
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+    /// Create pre-release versions with the given label, e.g., `rc`.
+    #[arg(long, value_name = "LABEL")]
+    pre: Option<Prerelease>,
+    /// Attach the given build metadata to all versions.
+    #[arg(long, value_name = "METADATA")]
+    build: Option<BuildMetadata>,
+    /// Sign release tags with the configured GPG or SSH key.
+    #[arg(short, long)]
+    sign: bool,
+    /// Release from any branch, even with uncommitted changes.
+    #[arg(short, long)]
+    force: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Arguments {
+    /// Returns the next version after applying the given increment.
+    ///
+    /// If a pre-release label is given, the next version is a pre-release of
+    /// the next version, or the next pre-release of the same version, and if
+    /// build metadata is given, it's attached to the next version.
+    fn next(&self, version: &Version, increment: Increment) -> Version {
+        let mut next = match &self.pre {
+            Some(label) => version.bump_pre(increment, label),
+            None => version.bump(increment),
+        };
+        if let Some(build) = &self.build {
+            next.build = build.clone();
+        }
+        next
+    }
+
+    /// Selects version increments for all packages affected by changes.
+    ///
+    /// Version increments given explicitly are always used as is. Otherwise,
+    /// the user is prompted to select from the suggested options, unless we're
+    /// running non-interactively, where we pick the highest option.
+    fn select<T>(
+        &self, dependents: &Dependents<T>,
+        increments: &mut [Option<Increment>],
+        overrides: &BTreeMap<String, Increment>,
+    ) -> Result
+    where
+        T: Manifest,
+    {
+        dependents.bump(increments, |suggestion| {
+            let name = suggestion.project().name().expect("invariant");
+            let version = suggestion.project().version().expect("invariant");
+
+            // If the version increment was given explicitly, or there's only a
+            // single option, there's nothing to choose, and if we're running
+            // non-interactively, we always pick the highest option
+            let options = suggestion.increments();
+            let option = match (overrides.get(name), options) {
+                (Some(&increment), _) => Some(Some(increment)),
+                (None, [option]) => Some(*option),
+                (None, [.., option]) if self.yes => Some(*option),
+                _ => None,
+            };
+
+            // Denote the next version of the package, if any
+            if let Some(option) = option {
+                if let Some(increment) = option {
+                    remark_bump(name, &self.next(version, increment))?;
+                }
+                return Ok(option);
+            }
+
+            // Otherwise, let the user select from the suggested options, which
+            // include keeping the current version of the package
+            let mut builder =
+                options.iter().fold(select(name), |builder, &bump| {
+                    if let Some(next) = bump {
+                        let version = self.next(version, next);
+                        builder.item(Some(next), version, next)
+                    } else {
+                        builder.item(None, version, "current")
+                    }
+                });
+
+            // Return selected version increment
+            Ok(builder.interact()?)
+        })?;
+
+        // No errors occurred
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -58,136 +180,575 @@ pub struct Arguments {
 impl<T> Command<T> for Arguments
 where
     T: Manifest,
+    Writer<T>: Writable,
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
-        // Resolve versions and create changeset, then determine all commits
-        // that were added after the latest version was released
-        let versions = context.repository.versions()?;
-        let mut changeset = Changeset::new(&context.workspace)?;
-        changeset.extend(versions.unreleased()?.flatten())?;
+        // Ensure we're releasing from a clean default or maintenance branch,
+        // unless forced, or doing a dry run, which doesn't change anything
+        if !(self.force || self.dry_run) {
+            ensure_releasable(&context)?;
+        }
+
+        // Determine version increments from all commits that were added after
+        // the latest version was released, depending on the versioning mode
+        let own = super::increments(&context)?;
+
+        // Ensure all version increment overrides refer to existing packages,
+        // since we'd otherwise silently ignore typos in package names
+        let overrides = self.bump.iter().cloned().collect::<BTreeMap<_, _>>();
+        if let Some(name) = overrides
+            .keys()
+            .find(|name| context.workspace.get(name).is_none())
+        {
+            return Err(Error::Package(name.clone()));
+        }
 
         // Obtain version increments, which denote which packages have changed,
-        // and abort immediately if there are no changes to release
-        let mut increments = changeset.increments().to_vec();
+        // and apply overrides, so packages without changes can be bumped too
+        let dependents = context.workspace.dependents()?;
+        let mut increments = own.clone();
+        for node in &dependents {
+            let name = dependents[node].name().expect("invariant");
+            if let Some(&increment) = overrides.get(name) {
+                increments[node] = Some(increment);
+            }
+        }
+
+        // Abort immediately if there are no changes to release
+        intro(style("Select version increments").dim())?;
         if increments.iter().all(Option::is_none) {
-            println!("no changes..."); // @todo
+            outro("Nothing to release")?;
             return Ok(());
         }
 
-        // @todo only run this when something changed...
-        intro(style("Select version increments").dim())?;
+        // Traverse dependents in topological order, and let the user select
+        // the version increment for each package that is affected by changes
+        self.select(&dependents, &mut increments, &overrides)?;
+
+        // Create writer from the selected version increments, and abort if no
+        // package is bumped, since all increments might have been deselected
+        let writer = context
+            .workspace
+            .apply_with(&increments, |version, increment| {
+                self.next(version, increment)
+            });
+        if writer.is_empty() {
+            outro("Nothing to release")?;
+            return Ok(());
+        }
 
-        //
-        let dependents = context.workspace.dependents()?;
-        dependents.bump(&mut increments, |suggestion| {
-            let bumps = suggestion.increments();
-            let name = suggestion.project().name().expect("invariant");
-            let version = suggestion.project().version().expect("invariant");
+        // Determine the release tags, and ensure they don't exist yet, before
+        // touching anything, since we'd otherwise fail after committing
+        let (tags, changelogs): (Vec<_>, Vec<_>) =
+            tags(&context, &dependents, &writer)?.into_iter().unzip();
+
+        // Obtain release summary from file or prompt for it, except for dry
+        // runs and when prompting is disabled, where we must never open the
+        // editor, so we just use the template
+        let template = &context.config.release.template;
+        let summary = match &self.message_file {
+            Some(path) => read_commit_message(path)?,
+            None if self.dry_run || self.yes => template.trim().to_string(),
+            None => prompt_commit_message(self.visual, template)?,
+        };
+
+        // Create commit message for release
+        let message = format!("{RELEASE}{}\n\n{summary}", tags.join(", "));
+
+        // In case of a dry run, collect all bumped packages together with the
+        // reason for their version increment, as well as all rewritten files,
+        // and print the release plan in the requested format
+        if self.dry_run {
+            let plan = Plan {
+                tags,
+                message,
+                packages: packages(
+                    &dependents,
+                    &writer,
+                    &own,
+                    &increments,
+                    &overrides,
+                ),
+                files: files(&context, &writer)?,
+            };
+
+            // Print release plan
+            outro("Dry run, nothing was changed")?;
+            match context.format {
+                Format::Json => {
+                    println!("{}", serde_json::to_string_pretty(&plan)?);
+                }
+                Format::Text => print!("{plan}"),
+            }
+            return Ok(());
+        }
+
+        // Back up all manifests and the lockfile, write the next versions and
+        // create the release commit. If any of those steps fails, the backup
+        // is restored and staged again, so the workspace and index are left
+        // as they were before the release
+        let mut backup = Backup::default();
+        for project in &context.workspace {
+            backup.add(project.path())?;
+        }
+        let lockfile = writer.lockfile(context.workspace.path());
+        backup.add(&lockfile)?;
+        if let Err(err) = commit(&context, &writer, &message) {
+            backup.restore()?;
+            for project in &context.workspace {
+                add(&context.repository, project.path())?;
+            }
+            if lockfile.exists() {
+                add(&context.repository, &lockfile)?;
+            }
+            return Err(err);
+        }
+
+        // Create annotated tags, whose messages consist of the release summary
+        // and changelog, signing them, if requested. Note that the release
+        // commit is kept if tagging fails, e.g., because signing failed, which
+        // must then be tagged manually, or reset with `git reset --hard HEAD~1`
+        // after deleting the tags that were already created
+        let sign = self.sign || context.config.release.sign;
+        for (tag, changelog) in tags.iter().zip(&changelogs) {
+            let message = if changelog.is_empty() {
+                summary.clone()
+            } else {
+                format!("{summary}\n\n{changelog}")
+            };
+            context.repository.tag(tag, message, sign)?;
+        }
+
+        // No errors occurred
+        outro(format!("Released {}", style(tags.join(", ")).green()))?;
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Ensures that the current branch and working tree are fit for a release.
+///
+/// Releases must be created from the default branch, or a maintenance branch
+/// matching one of the configured patterns, e.g., `release/1.x`, and without
+/// uncommitted changes, as they'd otherwise end up in the release commit.
+fn ensure_releasable<T>(context: &Context<T>) -> Result
+where
+    T: Manifest,
+{
+    let repository = &context.repository;
+    if !repository.on_default_branch()? {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &context.config.repository.release_branches {
+            builder.add(Glob::new(pattern).expect("invariant"));
+        }
+
+        // Ensure the current branch matches one of the maintenance patterns
+        let globs = builder.build().expect("invariant");
+        let branch = repository.current_branch()?;
+        match branch {
+            Some(branch) if globs.is_match(&branch) => {}
+            Some(branch) => return Err(Error::Branch(branch)),
+            None => return Err(Error::Branch(String::from("HEAD"))),
+        }
+    }
 
-            // yes, keep this...
-            if suggestion.increments().len() == 1 {
-                // @todo is the expext right here?
-                let increment = bumps[0].expect("invariant");
+    // Ensure there are no uncommitted or untracked changes
+    if !repository.is_clean()? {
+        return Err(Error::Dirty);
+    }
 
-                let x = format!(
-                    "{}\n{}",
-                    name,
-                    style(version.bump(increment)).dim()
-                ); // denote what bumped
-                remark(x)?;
+    // No errors occurred
+    Ok(())
+}
 
-                // Just keep the incrmen as is.. - todo...
-                return Ok(Some(increment));
+/// Returns the release tags, ensuring that none of them exists yet.
+///
+/// With lockstep versioning, the highest next version of all packages is
+/// used as the single tag, so the tag always matches the versions that
+/// are written to the manifests.
+/// With independent versioning, each bumped package is tagged with its
+/// next version, e.g., `ctrl-z@1.2.0`. Each tag is returned together with
+/// the changelog of its unreleased changes, which is part of its message.
+fn tags<T>(
+    context: &Context<T>, dependents: &Dependents<T>, writer: &Writer<T>,
+) -> Result<Vec<(String, String)>>
+where
+    T: Manifest,
+{
+    let mut tags = Vec::new();
+    match context.config.repository.versioning {
+        Versioning::Lockstep => {
+            let versions = context.repository.versions()?;
+            let iter = writer.iter().map(|(_, next)| next);
+            let version = iter.max().expect("invariant");
+
+            // Ensure the version doesn't exist yet
+            let tag = versions.tag(version);
+            if versions.contains(version) {
+                return Err(Error::Exists(tag));
             }
+            tags.push((tag, changelog(context, &versions, None)?));
+        }
+        Versioning::Independent => {
+            for node in dependents {
+                let name = dependents[node].name().expect("invariant");
+                let Some(version) = writer.get(name) else {
+                    continue;
+                };
+
+                // Ensure the version of the package doesn't exist yet
+                let versions = context.repository.package_versions(name)?;
+                let tag = versions.tag(version);
+                if versions.contains(version) {
+                    return Err(Error::Exists(tag));
+                }
+                tags.push((tag, changelog(context, &versions, Some(name))?));
+            }
+        }
+    }
 
-            //
-            let mut builder =
-                bumps.iter().fold(select(name), |builder, &bump| {
-                    if let Some(next) = bump {
-                        builder.item(Some(next), version.bump(next), next)
-                    } else {
-                        builder.item(None, version, "current")
-                    }
-                });
+    // Return release tags
+    Ok(tags)
+}
 
-            let x = builder.interact()?;
-            println!("selected: {x:?}");
+/// Returns the changelog of unreleased changes for the release tag message.
+///
+/// If a package is given, the changelog is narrowed down to the changes that
+/// affect the package, which is used for independent versioning.
+fn changelog<T>(
+    context: &Context<T>, versions: &Versions, package: Option<&str>,
+) -> Result<String>
+where
+    T: Manifest,
+{
+    let config = context.config.changeset();
+    let mut changeset = Changeset::with_config(&context.workspace, config)?;
+    changeset.extend(versions.unreleased()?.flatten())?;
+
+    // Narrow the changeset down to the revisions affecting the package
+    if let Some(name) = package {
+        let index = changeset.scopes().find(name).expect("invariant");
+        changeset.retain(|revision| revision.scopes().contains(&index));
+    }
 
-            //
-            Ok(x)
-        })?;
+    // Return changelog, which is empty if there are no relevant changes
+    let changelog = changeset.to_changelog();
+    if changelog.is_empty() {
+        Ok(String::new())
+    } else {
+        Ok(changelog.to_string())
+    }
+}
 
-        // let versions = manager.bump(|name, version, bumps| {
-        // })?;
+/// Parses a version increment for a package, e.g., `ctrl-z=minor`.
+fn parse_bump(
+    value: &str,
+) -> result::Result<(String, Increment), project::Error> {
+    let (name, increment) = value.split_once('=').unwrap_or((value, ""));
+    Ok((name.to_string(), increment.parse()?))
+}
 
-        outro("Done")?;
+/// Denotes the next version of a package.
+fn remark_bump(name: &str, next: &Version) -> io::Result<()> {
+    remark(format!("{name}\n{}", style(next).dim()))
+}
 
-        println!("increments {:#?}", increments);
+/// Returns the bumped packages of the release plan in topological order.
+fn packages<T>(
+    dependents: &Dependents<T>, writer: &Writer<T>, own: &[Option<Increment>],
+    increments: &[Option<Increment>], overrides: &BTreeMap<String, Increment>,
+) -> Vec<Package>
+where
+    T: Manifest,
+{
+    let mut packages = Vec::new();
+    for node in dependents {
+        let Some(increment) = increments[node] else {
+            continue;
+        };
+
+        // Packages are either bumped explicitly, because of their own changes,
+        // or because of the packages they depend on that are bumped as well
+        let project = dependents[node];
+        let name = project.name().expect("invariant");
+        let reason = if overrides.contains_key(name) {
+            Reason::Override
+        } else if own[node].is_some_and(|own| increment <= own) {
+            Reason::Changes
+        } else {
+            let iter = project.dependencies();
+            let iter = iter.filter(|name| writer.get(name).is_some());
+            Reason::Dependencies(iter.map(str::to_string).collect())
+        };
+
+        // Add package to release plan
+        let version = project.version().expect("invariant");
+        packages.push(Package {
+            name: name.to_string(),
+            from: version.clone(),
+            to: writer.get(name).expect("invariant").clone(),
+            increment,
+            reason,
+        });
+    }
 
-        // now, apply the given version increments to the project.
+    // Return bumped packages
+    packages
+}
 
-        // println!("versions: {:?}", versions);
+/// Returns the rewritten files of the release plan, without writing them.
+fn files<T>(context: &Context<T>, writer: &Writer<T>) -> Result<Vec<File>>
+where
+    T: Manifest,
+    Writer<T>: Writable,
+{
+    let mut files = Vec::new();
+    for project in &context.workspace {
+        let current = fs::read_to_string(project.path())?;
+        let next = writer.write(&current)?;
+        if next != current {
+            let path = relative(&context.repository, project.path())?;
+            files.push(File::new(path, &current, &next));
+        }
+    }
 
-        // let summary = prompt_commit_message(self.visual)?;
+    // Return rewritten files
+    Ok(files)
+}
 
-        // manager.update(versions, summary)?;
+/// Returns the given path relative to the repository root.
+fn relative(repository: &Repository, path: &Path) -> Result<PathBuf> {
+    let root = repository.path().canonicalize()?;
+    let path = path.canonicalize()?;
+    match path.strip_prefix(root) {
+        Ok(path) => Ok(path.to_path_buf()),
+        Err(_) => Ok(path),
+    }
+}
 
-        // No errors occurred
-        Ok(())
+/// Writes the next versions to all projects of the workspace, updates the
+/// lockfile, and creates the release commit with the given message.
+fn commit<T>(context: &Context<T>, writer: &Writer<T>, message: &str) -> Result
+where
+    T: Manifest,
+    Writer<T>: Writable,
+{
+    let lockfile = context.workspace.write(writer)?;
+    for project in &context.workspace {
+        add(&context.repository, project.path())?;
+    }
+    if let Some(lockfile) = lockfile {
+        add(&context.repository, &lockfile)?;
     }
+
+    // Create release commit from all staged files
+    context.repository.commit(message)?;
+
+    // No errors occurred
+    Ok(())
 }
 
-fn prepend_lines_with_quote(text: &str) -> String {
-    text.lines()
-        .map(|line| format!("> {}", line))
-        .collect::<Vec<_>>()
-        .join("\n")
+/// Stages the file at the given path.
+fn add(repository: &Repository, path: &Path) -> Result {
+    let path = relative(repository, path)?;
+    repository.add(path.to_string_lossy())?;
+
+    // No errors occurred
+    Ok(())
 }
 
-fn prompt_commit_message(visual: bool) -> Result<String> {
-    // Create a temporary file with template - read template and include string
-    // move this into our obligatory configuration file
+/// Reads the release summary from the file at the given path.
+fn read_commit_message(path: &Path) -> Result<String> {
+    let summary = fs::read_to_string(path)?;
+    let summary = summary.trim();
+    if summary.is_empty() {
+        Err(Error::Summary)
+    } else {
+        Ok(summary.to_string())
+    }
+}
+
+/// Prompts for the release summary using the editor.
+fn prompt_commit_message(visual: bool, template: &str) -> Result<String> {
     let mut temp = NamedTempFile::new()?;
-    writeln!(temp, "## Summary\n\n...\n\n### Highlights\n\n- ...")?;
+    temp.write_all(template.as_bytes())?;
+
+    // Obtain editor from environment, or fall back to vim - note that editors
+    // might be given with arguments, e.g., `code --wait`
+    let var = if visual { "VISUAL" } else { "EDITOR" };
+    let editor = env::var(var).unwrap_or_else(|_| "vim".to_string());
+    let mut parts = editor.split_whitespace();
+
+    // Open editor and wait for it to exit - VS Code must be told to wait
+    let program = parts.next().unwrap_or("vim");
+    let mut command = process::Command::new(program);
+    command.args(parts);
+    if program == "code" && !editor.contains("--wait") {
+        command.arg("--wait");
+    }
 
-    // Get editor from environment or use default
-    let editor = if visual {
-        std::env::var("VISUAL").unwrap_or_else(|_| "vim".to_string())
-    } else {
-        std::env::var("EDITOR").unwrap_or_else(|_| "vim".to_string())
-    };
-
-    // Open editor
-    let mut cmd = std::process::Command::new(&editor);
-    cmd.arg(temp.path());
-    if editor == "code" {
-        cmd.arg("--wait");
-    }
-    let status = cmd.status()?;
-    println!("status {:?}", status);
-    // check if the file is the same actually
+    // Ensure editor exited successfully
+    let status = command.arg(temp.path()).status()?;
     if !status.success() {
-        std::process::exit(1);
-        // return Err("Editor exited with non-zero status".into());
+        return Err(Error::Status(status));
     }
 
-    // Read the message back
-    let message = std::fs::read_to_string(temp.path())?;
+    // Read summary back, and ensure the template was actually edited, since
+    // we consider an unchanged or empty summary as an aborted release
+    let summary = fs::read_to_string(temp.path())?;
+    let summary = summary.trim();
+    if summary.is_empty() || summary == template.trim() {
+        Err(Error::Summary)
+    } else {
+        Ok(summary.to_string())
+    }
+}
 
-    // // Filter out comment lines and trim
-    // let message: String = content
-    //     .lines()
-    //     .filter(|line| !line.trim_start().starts_with('#'))
-    //     .collect::<Vec<_>>()
-    //     .join("\n")
-    //     .trim()
-    //     .to_string();
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
 
-    if message.is_empty() {
-        std::process::exit(1);
-        // return Err("Empty commit message".into());
-    }
+#[cfg(test)]
+mod tests {
+
+    mod execute {
+        use std::error::Error;
+        use std::fs;
+        use std::path::Path;
+        use std::process;
+        use tempfile::TempDir;
+
+        use ctrl_z_project::{Cargo, Workspace};
+        use ctrl_z_repository::{self as repository, Repository};
+
+        use crate::cli::command::version::create::Arguments;
+        use crate::cli::{self, Command, Config, Format, Versioning};
+        use crate::Context;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Runs git with the given arguments in the given directory.
+        fn git(root: &Path, args: &[&str]) -> Result<String> {
+            let output = process::Command::new("git")
+                .current_dir(root)
+                .args(args)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8(output.stdout)?)
+        }
+
+        /// Creates a workspace with a single package with the given version
+        /// and an unreleased feature, and returns the temporary directory.
+        fn setup(version: &str) -> Result<TempDir> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            fs::create_dir_all(root.join("packages/core/src"))?;
+            fs::write(
+                root.join("packages/core/Cargo.toml"),
+                format!(
+                    "[package]\nname = \"core\"\nversion = \"{version}\"\n"
+                ),
+            )?;
+            fs::write(
+                root.join("Cargo.toml"),
+                "[workspace]\nmembers = [\"packages/core\"]\n",
+            )?;
+
+            // Initialize repository and commit the feature
+            git(root, &["init", "--quiet"])?;
+            git(root, &["config", "user.name", "Alice"])?;
+            git(root, &["config", "user.email", "a@b.c"])?;
+            git(root, &["config", "commit.gpgsign", "false"])?;
+            git(root, &["config", "tag.gpgsign", "false"])?;
+            git(root, &["add", "."])?;
+            git(root, &["commit", "--quiet", "-m", "chore: initial commit"])?;
+            git(root, &["tag", "--annotate", "-m", "Release", "v1.0.0"])?;
+            fs::write(root.join("packages/core/src/lib.rs"), "")?;
+            git(root, &["add", "."])?;
+            git(root, &["commit", "--quiet", "-m", "feature: add parser"])?;
+            Ok(dir)
+        }
 
-    Ok(message)
+        /// Creates a release without prompting in the given directory.
+        fn release(root: &Path, config: Config) -> cli::Result {
+            let context = Context {
+                repository: Repository::open(root)?,
+                workspace: Workspace::<Cargo>::read(root.join("Cargo.toml"))?,
+                config,
+                format: Format::Text,
+            };
+            let args = Arguments {
+                visual: false,
+                yes: true,
+                bump: Vec::new(),
+                message_file: None,
+                dry_run: false,
+                pre: None,
+                build: None,
+                sign: false,
+                force: true,
+            };
+            args.execute(context)
+        }
+
+        #[test]
+        fn handles_yes_with_independent_versioning() -> Result {
+            let dir = setup("1.0.0")?;
+            let root = dir.path();
+            let mut config = Config::default();
+            config.repository.versioning = Versioning::Independent;
+            let template = config.release.template.trim().to_string();
+            release(root, config)?;
+
+            // Ensure the release commit uses the template as its summary, which
+            // means that the editor wasn't opened
+            let message = git(root, &["log", "-1", "--format=%B"])?;
+            assert!(message.starts_with(&format!(
+                "chore: release core@1.1.0\n\n{template}"
+            )));
+            assert_eq!(
+                git(root, &["tag", "--list", "core@*"])?,
+                "core@1.1.0\n"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_lockstep_versioning() -> Result {
+            let dir = setup("1.1.0")?;
+            let root = dir.path();
+            release(root, Config::default())?;
+
+            // Ensure the tag matches the version written to the manifest, and
+            // not the latest tag bumped by the version increment
+            let manifest = root.join("packages/core/Cargo.toml");
+            assert!(fs::read_to_string(manifest)?.contains("\"1.2.0\""));
+            assert_eq!(git(root, &["tag", "--list"])?, "v1.0.0\nv1.2.0\n");
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_failed_commit() -> Result {
+            let dir = setup("1.0.0")?;
+            let root = dir.path();
+            git(root, &["config", "commit.gpgsign", "true"])?;
+            git(root, &["config", "gpg.program", "false"])?;
+            assert!(matches!(
+                release(root, Config::default()),
+                Err(cli::Error::Repository(repository::Error::Status(_)))
+            ));
+
+            // Ensure the manifest and the index were restored
+            let manifest = root.join("packages/core/Cargo.toml");
+            assert!(fs::read_to_string(manifest)?.contains("\"1.0.0\""));
+            assert_eq!(git(root, &["status", "--porcelain"])?, "");
+            assert_eq!(git(root, &["tag", "--list"])?, "v1.0.0\n");
+            Ok(())
+        }
+    }
 }
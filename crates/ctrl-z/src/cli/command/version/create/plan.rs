@@ -0,0 +1,176 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Release plan.
+
+use semver::Version;
+use serde::Serialize;
+use similar::TextDiff;
+use std::fmt::{self, Write};
+use std::path::PathBuf;
+
+use ctrl_z_project::version::Increment;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Release plan.
+///
+/// The release plan describes everything that `version create` would do, i.e.,
+/// which packages are bumped and why, how manifests are rewritten, and which
+/// commit and tags are created, without touching the working tree.
+#[derive(Debug, Serialize)]
+pub struct Plan {
+    /// Release tags.
+    pub tags: Vec<String>,
+    /// Release commit message.
+    pub message: String,
+    /// Bumped packages in topological order.
+    pub packages: Vec<Package>,
+    /// Rewritten files.
+    pub files: Vec<File>,
+}
+
+/// Bumped package.
+#[derive(Debug, Serialize)]
+pub struct Package {
+    /// Package name.
+    pub name: String,
+    /// Current version.
+    pub from: Version,
+    /// Next version.
+    pub to: Version,
+    /// Version increment.
+    pub increment: Increment,
+    /// Reason for version increment.
+    pub reason: Reason,
+}
+
+/// Rewritten file.
+#[derive(Debug, Serialize)]
+pub struct File {
+    /// File path, relative to the repository root.
+    pub path: PathBuf,
+    /// Unified diff.
+    pub diff: String,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Reason for version increment.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase", tag = "kind", content = "packages")]
+pub enum Reason {
+    /// Package has changes of its own.
+    Changes,
+    /// Package depends on bumped packages.
+    Dependencies(Vec<String>),
+    /// Version increment was given explicitly.
+    Override,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl File {
+    /// Creates a rewritten file from its current and next content.
+    pub fn new(path: PathBuf, from: &str, to: &str) -> Self {
+        let name = path.to_string_lossy();
+        let diff = TextDiff::from_lines(from, to)
+            .unified_diff()
+            .header(&format!("a/{name}"), &format!("b/{name}"))
+            .to_string();
+
+        // Create and return file
+        Self { path, diff }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Plan {
+    /// Formats the release plan for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Release {}", self.tags.join(", "))?;
+
+        // Write bumped packages
+        f.write_str("\nPackages:\n")?;
+        for package in &self.packages {
+            writeln!(
+                f,
+                "  {} {} -> {} ({}, {})",
+                package.name,
+                package.from,
+                package.to,
+                package.increment,
+                package.reason
+            )?;
+        }
+
+        // Write commit message, indented
+        f.write_str("\nCommit:\n")?;
+        for line in self.message.lines() {
+            if line.is_empty() {
+                f.write_char('\n')?;
+            } else {
+                writeln!(f, "  {line}")?;
+            }
+        }
+
+        // Write tags and diffs of rewritten files
+        f.write_str("\nTags:\n")?;
+        for tag in &self.tags {
+            writeln!(f, "  {tag}")?;
+        }
+        for file in &self.files {
+            f.write_char('\n')?;
+            f.write_str(&file.diff)?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Reason {
+    /// Formats the reason for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::Changes => f.write_str("changes"),
+            Reason::Dependencies(names) => {
+                write!(f, "dependencies: {}", names.join(", "))
+            }
+            Reason::Override => f.write_str("override"),
+        }
+    }
+}
@@ -26,11 +26,14 @@
 //! List versions in reverse chronological order.
 
 use clap::Args;
+use semver::Version;
+use serde::Serialize;
 use std::fmt::Debug;
+use std::result;
 
 use ctrl_z_project::Manifest;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result, Versioning};
 use crate::Context;
 
 // ----------------------------------------------------------------------------
@@ -43,6 +46,22 @@ pub struct Arguments {
     /// Show only the latest version.
     #[arg(short, long)]
     latest: bool,
+    /// Only list versions of the package with the given name.
+    #[arg(short, long)]
+    package: Option<String>,
+}
+
+/// Release.
+#[derive(Debug, Serialize)]
+struct Release<'a> {
+    /// Version.
+    version: &'a Version,
+    /// Tag name.
+    tag: String,
+    /// Commit identifier.
+    id: String,
+    /// Release date.
+    date: String,
 }
 
 // ----------------------------------------------------------------------------
@@ -55,14 +74,44 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
-        // Resolve and list all versions, and abort after writing the latest
-        // version to standard out if only the latest version is requested
-        let versions = context.repository.versions()?;
-        for (version, _) in &versions {
-            println!("v{version}");
-            if self.latest {
-                break;
+        // With independent versioning, each package has its own series of
+        // tags, so unless a package is given, we list the versions of all
+        // packages, one package after another
+        let sets = match (&self.package, context.config.repository.versioning) {
+            (None, Versioning::Independent) => context
+                .workspace
+                .packages()
+                .map(|(_, name)| context.repository.package_versions(name))
+                .collect::<result::Result<Vec<_>, _>>()?,
+            (package, _) => {
+                vec![super::versions(&context, package.as_deref())?]
             }
+        };
+
+        // Resolve and list all versions, and continue with the next version
+        // set after writing the latest version if only that is requested
+        let mut releases = Vec::new();
+        for versions in &sets {
+            for (version, id) in versions {
+                let tag = versions.tag(version);
+                let date = versions.date(version).expect("invariant");
+                match context.format {
+                    Format::Json => {
+                        let id = id.to_string();
+                        let date = date.to_string();
+                        releases.push(Release { version, tag, id, date });
+                    }
+                    Format::Text => println!("{tag} {date}"),
+                }
+                if self.latest {
+                    break;
+                }
+            }
+        }
+
+        // Write releases to standard out, if requested
+        if context.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&releases)?);
         }
 
         // No errors occurred
@@ -0,0 +1,324 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Undo the latest release, unless it was already pushed.
+
+use clap::Args;
+use cliclack::{intro, outro};
+use console::style;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+use ctrl_z_project::Manifest;
+
+use crate::cli::{Command, Error, Format, Result};
+use crate::Context;
+
+use super::RELEASE;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Undo the latest release, unless it was already pushed.
+#[derive(Args, Debug)]
+pub struct Arguments {
+    /// Print what would be undone without changing anything.
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+}
+
+/// Release to undo.
+#[derive(Debug, Serialize)]
+struct Release {
+    /// Release commit identifier.
+    id: String,
+    /// Release commit summary.
+    summary: String,
+    /// Release tags.
+    tags: Vec<String>,
+    /// Restored files, relative to the repository root.
+    files: Vec<PathBuf>,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Arguments
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    ///
+    /// Only the latest release can be undone, i.e., `HEAD` must be a release
+    /// commit created by `version create`, and all of its tags must point to
+    /// it. Releases that were already pushed are never undone, since rewriting
+    /// published history would break everybody who fetched it.
+    fn execute(&self, context: Context<T>) -> Result {
+        let repository = &context.repository;
+        let head = repository.find("HEAD")?;
+        let Some(names) = head.summary().strip_prefix(RELEASE) else {
+            return Err(Error::Release);
+        };
+
+        // Ensure all tags of the release exist and point to the release commit,
+        // since we'd otherwise delete tags that belong to other commits
+        let mut tags = Vec::new();
+        for name in names.split(", ") {
+            match repository.find(format!("refs/tags/{name}")) {
+                Ok(commit) if commit == head => tags.push(name.to_string()),
+                _ => return Err(Error::Release),
+            }
+        }
+
+        // Ensure the release commit is local-only, i.e., it's not contained in
+        // any remote-tracking branch, as of the last fetch, and that none of
+        // its tags were pushed, which might happen without the commit
+        let id = head.id();
+        if repository.is_pushed(&id)? {
+            return Err(Error::Pushed(id.short()));
+        }
+        for tag in &tags {
+            if repository.is_tag_pushed(tag)? {
+                return Err(Error::Pushed(tag.clone()));
+            }
+        }
+
+        // Resolve the commit before the release, which we reset to, before
+        // touching anything, since the release might be the initial commit
+        let parent = repository.find("HEAD~1")?;
+
+        // Collect files changed by the release commit, i.e., manifests, the
+        // lockfile and changelogs, which are restored by resetting the commit
+        let release = Release {
+            id: id.to_string(),
+            summary: head.summary().to_string(),
+            tags,
+            files: head.deltas()?.map(|delta| delta.path().clone()).collect(),
+        };
+
+        // In case of a dry run, print what would be undone
+        intro(style("Undo release").dim())?;
+        if self.dry_run {
+            outro("Dry run, nothing was changed")?;
+            match context.format {
+                Format::Json => {
+                    println!("{}", serde_json::to_string_pretty(&release)?);
+                }
+                Format::Text => print!("{release}"),
+            }
+            return Ok(());
+        }
+
+        // Ensure there are no uncommitted changes, as they'd be discarded
+        if !repository.is_clean()? {
+            return Err(Error::Dirty);
+        }
+
+        // Reset to the commit before the release, and only then delete the
+        // release tags, so a failed reset leaves the release intact
+        repository.reset(parent.id().to_string())?;
+        for tag in &release.tags {
+            repository.delete_tag(tag)?;
+        }
+
+        // No errors occurred
+        let tags = release.tags.join(", ");
+        outro(format!("Undid release {}", style(tags).green()))?;
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Release {
+    /// Formats the release to undo for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Undo release {}", self.tags.join(", "))?;
+
+        // Write release commit and tags
+        writeln!(f, "\nCommit:\n  {:.7} {}", self.id, self.summary)?;
+        f.write_str("\nTags:\n")?;
+        for tag in &self.tags {
+            writeln!(f, "  {tag}")?;
+        }
+
+        // Write restored files
+        f.write_str("\nFiles:\n")?;
+        for path in &self.files {
+            writeln!(f, "  {}", path.display())?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod execute {
+        use std::error::Error;
+        use std::fs;
+        use std::path::Path;
+        use std::process;
+        use tempfile::TempDir;
+
+        use ctrl_z_project::{Cargo, Workspace};
+        use ctrl_z_repository::Repository;
+
+        use crate::cli::command::version::undo::Arguments;
+        use crate::cli::{self, Command, Config, Format};
+        use crate::Context;
+
+        /// Test result.
+        type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+        /// Runs git with the given arguments in the given directory.
+        fn git(root: &Path, args: &[&str]) -> Result<String> {
+            let output = process::Command::new("git")
+                .current_dir(root)
+                .args(args)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8(output.stdout)?)
+        }
+
+        /// Writes the manifest of the workspace with the given version.
+        fn write(root: &Path, version: &str) -> Result {
+            fs::write(
+                root.join("Cargo.toml"),
+                format!(
+                    "[package]\nname = \"core\"\nversion = \"{version}\"\n"
+                ),
+            )?;
+            Ok(())
+        }
+
+        /// Creates a repository with an initial commit, followed by a release
+        /// commit tagged `v1.1.0`, and returns the temporary directory.
+        fn setup() -> Result<TempDir> {
+            let dir = TempDir::new()?;
+            let root = dir.path();
+            git(root, &["init", "--quiet"])?;
+            git(root, &["config", "user.name", "Alice"])?;
+            git(root, &["config", "user.email", "a@b.c"])?;
+            git(root, &["config", "commit.gpgsign", "false"])?;
+            git(root, &["config", "tag.gpgsign", "false"])?;
+            fs::create_dir(root.join("src"))?;
+            fs::write(root.join("src/lib.rs"), "")?;
+            write(root, "1.0.0")?;
+            git(root, &["add", "."])?;
+            git(root, &["commit", "--quiet", "-m", "feature: add parser"])?;
+
+            // Create release commit and tag
+            write(root, "1.1.0")?;
+            git(root, &["commit", "--quiet", "-am", "chore: release v1.1.0"])?;
+            git(root, &["tag", "--annotate", "-m", "Release", "v1.1.0"])?;
+            Ok(dir)
+        }
+
+        /// Adds a bare repository as the `origin` remote of the given one.
+        fn remote(root: &Path, dir: &TempDir) -> Result {
+            let path = dir.path().to_string_lossy();
+            git(dir.path(), &["init", "--quiet", "--bare"])?;
+            git(root, &["remote", "add", "origin", &path])?;
+            Ok(())
+        }
+
+        /// Undoes the latest release in the given directory.
+        fn undo(root: &Path) -> cli::Result {
+            let context = Context {
+                repository: Repository::open(root)?,
+                workspace: Workspace::<Cargo>::read(root.join("Cargo.toml"))?,
+                config: Config::default(),
+                format: Format::Text,
+            };
+            Arguments { dry_run: false }.execute(context)
+        }
+
+        #[test]
+        fn handles_release() -> Result {
+            let dir = setup()?;
+            let root = dir.path();
+            undo(root)?;
+            assert_eq!(
+                git(root, &["log", "--format=%s"])?,
+                "feature: add parser\n"
+            );
+            assert_eq!(git(root, &["tag", "--list"])?, "");
+            assert!(fs::read_to_string(root.join("Cargo.toml"))?
+                .contains("version = \"1.0.0\""));
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_non_release() -> Result {
+            let dir = setup()?;
+            let root = dir.path();
+            git(
+                root,
+                &["commit", "--quiet", "--allow-empty", "-m", "fix: x"],
+            )?;
+            assert!(matches!(undo(root), Err(cli::Error::Release)));
+            assert_eq!(git(root, &["tag", "--list"])?, "v1.1.0\n");
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_pushed_commit() -> Result {
+            let dir = setup()?;
+            let root = dir.path();
+            let bare = TempDir::new()?;
+            remote(root, &bare)?;
+            git(root, &["push", "--quiet", "origin", "HEAD:master"])?;
+            git(root, &["fetch", "--quiet", "origin"])?;
+            assert!(matches!(undo(root), Err(cli::Error::Pushed(_))));
+            assert_eq!(git(root, &["tag", "--list"])?, "v1.1.0\n");
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_pushed_tag() -> Result {
+            let dir = setup()?;
+            let root = dir.path();
+            let bare = TempDir::new()?;
+            remote(root, &bare)?;
+            git(root, &["push", "--quiet", "origin", "v1.1.0"])?;
+            assert!(matches!(
+                undo(root),
+                Err(cli::Error::Pushed(tag)) if tag == "v1.1.0"
+            ));
+            assert_eq!(git(root, &["tag", "--list"])?, "v1.1.0\n");
+            Ok(())
+        }
+    }
+}
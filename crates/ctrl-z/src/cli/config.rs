@@ -0,0 +1,464 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Configuration.
+
+use globset::Glob;
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::result;
+
+use ctrl_z_changeset as changeset;
+use ctrl_z_changeset::change::Kind;
+use ctrl_z_changeset::changelog::Category;
+use ctrl_z_changeset::config::{Mode, Setting};
+use ctrl_z_repository::forge;
+
+use super::{Error, Result};
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Configuration file name.
+const FILE: &str = "ctrl-z.toml";
+
+/// Default release note template.
+const TEMPLATE: &str = "## Summary\n\n...\n\n### Highlights\n\n- ...\n";
+
+/// Supported kinds of changes, as listed in errors for unknown kinds.
+const KINDS: &str = "`feature`, `fix`, `performance`, `refactor`, `build`, \
+                     `docs`, `style`, `test` or `chore`";
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Configuration.
+///
+/// The configuration is read from `ctrl-z.toml` next to the workspace root,
+/// and every setting is optional, falling back to sensible defaults. Unknown
+/// keys and invalid values are rejected with an error pointing at the key.
+///
+/// ```toml
+/// [repository]
+/// branch = "main"
+/// release-branches = ["release/*", "maintenance/*"]
+/// tag-prefix = "v"
+/// versioning = "independent"
+/// url = "https://git.example.com/owner/repo"
+/// forge = "gitlab"
+///
+/// [release]
+/// template = "## Summary\n\n...\n"
+/// sign = true
+///
+/// [scopes]
+/// mode = "override"
+///
+/// [kinds]
+/// docs = "patch"
+/// chore = "disabled"
+///
+/// [categories]
+/// fix = "Fixes"
+/// ```
+///
+/// Note that the kinds of changes are a closed set, i.e., `feature`, `fix`,
+/// `performance`, `refactor`, `build`, `docs`, `style`, `test` and `chore`,
+/// which can only be configured, but not extended. Custom kinds like `deps`
+/// are rejected in `[kinds]` with an error naming the supported kinds, and in
+/// commit messages alike.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Repository settings.
+    pub repository: RepositoryConfig,
+    /// Release settings.
+    pub release: ReleaseConfig,
+    /// Scope settings.
+    pub scopes: ScopesConfig,
+    /// Change kind settings.
+    #[serde(deserialize_with = "kinds")]
+    pub kinds: BTreeMap<Kind, Setting>,
+    /// Section category titles.
+    pub categories: BTreeMap<Category, Title>,
+}
+
+/// Repository settings.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RepositoryConfig {
+    /// Default branch, overriding detection from the repository.
+    #[serde(deserialize_with = "branch")]
+    pub branch: Option<String>,
+    /// Maintenance branch patterns, from which releases can be created.
+    #[serde(deserialize_with = "patterns")]
+    pub release_branches: Vec<String>,
+    /// Tag prefix.
+    #[serde(deserialize_with = "prefix")]
+    pub tag_prefix: String,
+    /// Versioning mode.
+    pub versioning: Versioning,
+    /// Forge base URL, overriding detection from the `origin` remote.
+    pub url: Option<String>,
+    /// Forge kind, detected from the base URL if not given.
+    pub forge: Option<forge::Kind>,
+}
+
+/// Scope settings.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScopesConfig {
+    /// Scope mode.
+    pub mode: Mode,
+}
+
+/// Section category title.
+#[derive(Clone, Debug)]
+pub struct Title(String);
+
+/// Release settings.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReleaseConfig {
+    /// Release note template.
+    #[serde(deserialize_with = "template")]
+    pub template: String,
+    /// Whether to sign release tags.
+    pub sign: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Versioning mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Versioning {
+    /// All packages share a single series of tags, e.g., `v1.2.0`.
+    #[default]
+    Lockstep,
+    /// Each package has its own series of tags, e.g., `ctrl-z@1.2.0`.
+    Independent,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Config {
+    /// Attempts to read the configuration from the given directory.
+    ///
+    /// If the directory doesn't contain a configuration file, the default
+    /// configuration is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Config`], if the configuration file is not
+    /// valid, and [`Error::Io`] if it can't be read.
+    pub fn read(path: &Path) -> Result<Self> {
+        let path = path.join(FILE);
+        match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| Error::Config(path, Box::new(err))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns the changeset configuration.
+    pub fn changeset(&self) -> changeset::Config {
+        let iter = self.categories.iter();
+        let categories =
+            iter.map(|(category, title)| (*category, title.0.clone()));
+        changeset::Config::new(self.kinds.clone(), categories.collect())
+            .with_mode(self.scopes.mode)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Default for RepositoryConfig {
+    /// Creates the default repository settings.
+    fn default() -> Self {
+        Self {
+            branch: None,
+            release_branches: vec![String::from("release/*")],
+            tag_prefix: String::from("v"),
+            versioning: Versioning::default(),
+            url: None,
+            forge: None,
+        }
+    }
+}
+
+impl Default for ReleaseConfig {
+    /// Creates the default release settings.
+    fn default() -> Self {
+        Self {
+            template: String::from(TEMPLATE),
+            sign: false,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Title {
+    /// Deserializes and validates a section category title.
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        if value.trim().is_empty() {
+            Err(de::Error::custom("title must not be empty"))
+        } else if value.contains('\n') {
+            Err(de::Error::custom("title must not contain line breaks"))
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Deserializes and validates a branch name.
+fn branch<'de, D>(deserializer: D) -> result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    if value.trim().is_empty() {
+        Err(de::Error::custom("branch must not be empty"))
+    } else if value.chars().any(char::is_whitespace) {
+        Err(de::Error::custom("branch must not contain whitespace"))
+    } else {
+        Ok(Some(value))
+    }
+}
+
+/// Deserializes and validates a list of branch patterns.
+fn patterns<'de, D>(deserializer: D) -> result::Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values = Vec::<String>::deserialize(deserializer)?;
+    for value in &values {
+        if value.chars().any(char::is_whitespace) {
+            return Err(de::Error::custom(
+                "branch pattern must not contain whitespace",
+            ));
+        }
+        Glob::new(value).map_err(de::Error::custom)?;
+    }
+    Ok(values)
+}
+
+/// Deserializes and validates a tag prefix.
+///
+/// Since the prefix is used in a glob pattern to find matching tags, it must
+/// not contain any glob characters, and it must not end in a digit, as the
+/// version could not be told apart from the prefix otherwise.
+fn prefix<'de, D>(deserializer: D) -> result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    if value.contains(['*', '?', '[', ']', '\\']) {
+        Err(de::Error::custom(
+            "tag prefix must not contain glob characters",
+        ))
+    } else if value.chars().any(char::is_whitespace) {
+        Err(de::Error::custom("tag prefix must not contain whitespace"))
+    } else if value.ends_with(|char: char| char.is_ascii_digit()) {
+        Err(de::Error::custom("tag prefix must not end with a digit"))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Deserializes and validates change kind settings.
+///
+/// Since kinds of changes can't be extended, unknown kinds are rejected with
+/// an error that names all supported kinds, so typos are easy to spot.
+fn kinds<'de, D>(
+    deserializer: D,
+) -> result::Result<BTreeMap<Kind, Setting>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values = BTreeMap::<String, Setting>::deserialize(deserializer)?;
+    let mut kinds = BTreeMap::new();
+    for (key, setting) in values {
+        let Ok(kind) = key.parse::<Kind>() else {
+            return Err(de::Error::custom(format!(
+                "unknown kind `{key}`, expected one of {KINDS}, \
+                 as custom kinds are not supported"
+            )));
+        };
+        kinds.insert(kind, setting);
+    }
+    Ok(kinds)
+}
+
+/// Deserializes and validates a release note template.
+fn template<'de, D>(deserializer: D) -> result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    if value.trim().is_empty() {
+        Err(de::Error::custom("template must not be empty"))
+    } else {
+        Ok(value)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod deserialize {
+        use crate::cli::config::{Config, Versioning, KINDS};
+
+        /// Parses the given configuration, returning the error message.
+        fn error(value: &str) -> String {
+            toml::from_str::<Config>(value)
+                .expect_err("invalid configuration")
+                .to_string()
+        }
+
+        #[test]
+        fn handles_defaults() -> Result<(), toml::de::Error> {
+            let config = toml::from_str::<Config>("")?;
+            assert_eq!(config.repository.branch, None);
+            assert_eq!(config.repository.release_branches, ["release/*"]);
+            assert_eq!(config.repository.tag_prefix, "v");
+            assert_eq!(config.repository.versioning, Versioning::Lockstep);
+            assert!(!config.release.sign);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_settings() -> Result<(), toml::de::Error> {
+            let config = toml::from_str::<Config>(
+                "[repository]\n\
+                 branch = \"main\"\n\
+                 release-branches = [\"maintenance/*\"]\n\
+                 tag-prefix = \"release-\"\n\
+                 versioning = \"independent\"\n\
+                 [kinds]\n\
+                 docs = \"patch\"\n\
+                 [categories]\n\
+                 fix = \"Fixes\"\n",
+            )?;
+            assert_eq!(config.repository.branch.as_deref(), Some("main"));
+            assert_eq!(config.repository.release_branches, ["maintenance/*"]);
+            assert_eq!(config.repository.tag_prefix, "release-");
+            assert_eq!(config.repository.versioning, Versioning::Independent);
+            assert_eq!(config.kinds.len(), 1);
+            assert_eq!(config.categories.len(), 1);
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_patterns() {
+            for (value, message) in [
+                ("[\"release/*\", \"a b\"]", "must not contain whitespace"),
+                ("[\"release/[\"]", "unclosed character class"),
+            ] {
+                let err =
+                    error(&format!("[repository]\nrelease-branches = {value}"));
+                assert!(err.contains(message), "{err}");
+            }
+        }
+
+        #[test]
+        fn errors_on_invalid_prefix() {
+            for (value, message) in [
+                ("v*", "must not contain glob characters"),
+                ("release v", "must not contain whitespace"),
+                ("v1", "must not end with a digit"),
+            ] {
+                let err =
+                    error(&format!("[repository]\ntag-prefix = {value:?}"));
+                assert!(err.contains(message), "{err}");
+            }
+        }
+
+        #[test]
+        fn errors_on_invalid_branch() {
+            for (value, message) in [
+                ("", "must not be empty"),
+                ("my branch", "must not contain whitespace"),
+            ] {
+                let err = error(&format!("[repository]\nbranch = {value:?}"));
+                assert!(err.contains(message), "{err}");
+            }
+        }
+
+        #[test]
+        fn errors_on_invalid_title() {
+            let err = error("[categories]\nfix = \"  \"");
+            assert!(err.contains("title must not be empty"), "{err}");
+        }
+
+        #[test]
+        fn errors_on_unknown_fields() {
+            for value in [
+                "unknown = true",
+                "[repository]\nunknown = true",
+                "[release]\nunknown = true",
+                "[scopes]\nunknown = true",
+            ] {
+                let err = error(value);
+                assert!(err.contains("unknown field `unknown`"), "{err}");
+            }
+        }
+
+        #[test]
+        fn errors_on_unknown_kinds() {
+            let err = error("[kinds]\ndeps = \"patch\"");
+            assert!(err.contains("unknown kind `deps`"), "{err}");
+            assert!(err.contains(KINDS), "{err}");
+        }
+    }
+}
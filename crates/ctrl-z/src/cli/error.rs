@@ -25,10 +25,12 @@
 
 //! Command error.
 
-use std::{io, result};
+use std::path::PathBuf;
+use std::{io, process, result};
 use thiserror::Error;
 
 use ctrl_z_changeset as changeset;
+use ctrl_z_changeset::change;
 use ctrl_z_project as project;
 use ctrl_z_repository as repository;
 
@@ -45,6 +47,12 @@ pub enum Error {
     /// Version error.
     #[error(transparent)]
     Version(#[from] semver::Error),
+    /// JSON error.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// Configuration error.
+    #[error("invalid configuration in {path}\n{err}", path = .0.display(), err = .1)]
+    Config(PathBuf, Box<toml::de::Error>),
     /// Changeset error.
     #[error(transparent)]
     Changeset(#[from] changeset::Error),
@@ -54,6 +62,45 @@ pub enum Error {
     /// Repository error.
     #[error(transparent)]
     Repository(#[from] repository::Error),
+    /// Invalid commit message.
+    #[error("invalid commit message: {0}")]
+    Change(#[from] change::Error),
+    /// Invalid commit messages.
+    #[error("{0} of {1} commits are invalid")]
+    Commits(usize, usize),
+    /// Lightweight release tags.
+    #[error("{0} of {1} release tags are not annotated")]
+    Tags(usize, usize),
+    /// Process exited with status.
+    #[error("process exited with status {0}")]
+    Status(process::ExitStatus),
+    /// Missing summary.
+    #[error("missing summary")]
+    Summary,
+    /// Unknown package.
+    #[error("unknown package: {0}")]
+    Package(String),
+    /// Hook not managed by us.
+    #[error("hook not managed by ctrl-z: {0}")]
+    Hook(PathBuf),
+    /// Version already exists.
+    #[error("version {0} already exists")]
+    Exists(String),
+    /// Release from unexpected branch.
+    #[error("not on default or release branch: {0}")]
+    Branch(String),
+    /// Uncommitted changes.
+    #[error("working tree has uncommitted changes")]
+    Dirty,
+    /// No release to undo.
+    #[error("HEAD is not a release commit")]
+    Release,
+    /// Release already pushed.
+    #[error("release {0} was already pushed")]
+    Pushed(String),
+    /// Missing package for independent versioning.
+    #[error("package must be given for independent versioning")]
+    Versioning,
 }
 
 // ----------------------------------------------------------------------------
@@ -32,7 +32,7 @@ use ctrl_z_repository::Repository;
 
 mod cli;
 
-use cli::{Cli, Result};
+use cli::{Cli, Config, Format, Result};
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -48,6 +48,10 @@ where
     repository: Repository,
     /// Workspace.
     workspace: Workspace<T>,
+    /// Configuration.
+    config: Config,
+    /// Output format.
+    format: Format,
 }
 
 // ----------------------------------------------------------------------------